@@ -0,0 +1,171 @@
+//! Tokio async I/O support for `Or`, behind the `tokio` feature.
+
+use std::io::{self, IoSlice, SeekFrom};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
+
+use Or;
+
+/// `Or` is an async reader if both sides are async readers.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate or;
+/// # extern crate tokio;
+/// # use or::Or;
+/// use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
+/// use tokio::runtime::Builder;
+///
+/// # fn main() {
+/// let rt = Builder::new_current_thread().build().unwrap();
+/// let (client, server) = io::duplex(64);
+///
+/// let mut client: Or<io::DuplexStream, io::Sink> = Or::A(client);
+/// let mut server: Or<io::DuplexStream, io::Empty> = Or::A(server);
+///
+/// rt.block_on(client.write_all(b"ping")).unwrap();
+///
+/// let mut buf = [0; 4];
+/// rt.block_on(server.read_exact(&mut buf)).unwrap();
+/// assert_eq!(&buf, b"ping");
+/// # }
+/// ```
+impl<A, B> AsyncRead for Or<A, B>
+where A: AsyncRead, B: AsyncRead {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context, buf: &mut ReadBuf)
+                 -> Poll<io::Result<()>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_read(cx, buf))
+    }
+}
+
+/// `Or` is a buffered async reader if both sides are buffered async readers.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate or;
+/// # extern crate tokio;
+/// # use or::Or;
+/// use tokio::io::{self, AsyncBufReadExt, AsyncWriteExt, BufReader};
+/// use tokio::runtime::Builder;
+///
+/// # fn main() {
+/// let rt = Builder::new_current_thread().build().unwrap();
+/// let (mut client, server) = io::duplex(64);
+///
+/// let mut server: Or<BufReader<io::DuplexStream>, &[u8]> = Or::A(BufReader::new(server));
+///
+/// rt.block_on(client.write_all(b"one\ntwo\n")).unwrap();
+///
+/// let mut line = String::new();
+/// rt.block_on(server.read_line(&mut line)).unwrap();
+/// assert_eq!(line, "one\n");
+///
+/// let mut rest: Or<BufReader<io::DuplexStream>, &[u8]> = Or::B(&b"three\n"[..]);
+/// line.clear();
+/// rt.block_on(rest.read_line(&mut line)).unwrap();
+/// assert_eq!(line, "three\n");
+/// # }
+/// ```
+impl<A, B> AsyncBufRead for Or<A, B>
+where A: AsyncBufRead, B: AsyncBufRead {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<&[u8]>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_fill_buf(cx))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        for_both!(self.as_pin_mut(), inner => inner.consume(amt))
+    }
+}
+
+/// `Or` is an async writer if both sides are async writers.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate or;
+/// # extern crate tokio;
+/// # use or::Or;
+/// use std::io::IoSlice;
+/// use tokio::io::{self, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+/// use tokio::runtime::Builder;
+///
+/// # fn main() {
+/// let rt = Builder::new_current_thread().build().unwrap();
+/// let (client, mut server) = io::duplex(64);
+///
+/// let mut client: Or<io::DuplexStream, io::Sink> = Or::A(client);
+/// let duplex = client.as_ref().a().unwrap();
+/// assert_eq!(client.is_write_vectored(), duplex.is_write_vectored());
+///
+/// let bufs = [IoSlice::new(b"hello, "), IoSlice::new(b"world")];
+/// let written = rt.block_on(client.write_vectored(&bufs)).unwrap();
+/// rt.block_on(client.shutdown()).unwrap();
+///
+/// let mut received = Vec::new();
+/// rt.block_on(server.read_to_end(&mut received)).unwrap();
+/// assert_eq!(received.len(), written);
+/// assert!(b"hello, world".starts_with(&received));
+/// # }
+/// ```
+impl<A, B> AsyncWrite for Or<A, B>
+where A: AsyncWrite, B: AsyncWrite {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8])
+                  -> Poll<io::Result<usize>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_write(cx, buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_flush(cx))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_shutdown(cx))
+    }
+
+    fn poll_write_vectored(self: Pin<&mut Self>, cx: &mut Context, bufs: &[IoSlice])
+                           -> Poll<io::Result<usize>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_write_vectored(cx, bufs))
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        for_both!(*self, ref inner => inner.is_write_vectored())
+    }
+}
+
+/// `Or` is async seekable if both sides are async seekable.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate or;
+/// # extern crate tokio;
+/// # use or::Or;
+/// use std::io::{Cursor, SeekFrom};
+/// use tokio::io::{AsyncReadExt, AsyncSeekExt};
+/// use tokio::runtime::Builder;
+///
+/// # fn main() {
+/// let rt = Builder::new_current_thread().build().unwrap();
+/// let mut x: Or<Cursor<Vec<u8>>, Cursor<&[u8]>> = Or::B(Cursor::new(&b"abcdef"[..]));
+///
+/// assert_eq!(rt.block_on(x.seek(SeekFrom::Start(4))).unwrap(), 4);
+///
+/// let mut rest = String::new();
+/// rt.block_on(x.read_to_string(&mut rest)).unwrap();
+/// assert_eq!(rest, "ef");
+/// # }
+/// ```
+impl<A, B> AsyncSeek for Or<A, B>
+where A: AsyncSeek, B: AsyncSeek {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        for_both!(self.as_pin_mut(), inner => inner.start_seek(position))
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<u64>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_complete(cx))
+    }
+}
@@ -0,0 +1,181 @@
+//! Lossless conversions between `Or` and other two-way types in `std`.
+
+use std::ops::ControlFlow;
+use std::task::Poll;
+
+use Or;
+
+impl<A, B> Or<A, B> {
+    /// Convert from `Or<A, B>` to `Result<A, B>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// assert_eq!(x.into_result(), Ok(2));
+    ///
+    /// let y: Or<i32, &str> = Or::B("no");
+    /// assert_eq!(y.into_result(), Err("no"));
+    /// ```
+    pub fn into_result(self) -> Result<A, B> {
+        match self {
+            Or::A(a) => Ok(a),
+            Or::B(b) => Err(b)
+        }
+    }
+
+    /// Convert from `Result<A, B>` to `Or<A, B>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Result<i32, &str> = Ok(2);
+    /// assert_eq!(Or::from_result(x), Or::A(2));
+    ///
+    /// let y: Result<i32, &str> = Err("no");
+    /// assert_eq!(Or::from_result(y), Or::B("no"));
+    /// ```
+    pub fn from_result(result: Result<A, B>) -> Self {
+        match result {
+            Ok(a) => Or::A(a),
+            Err(b) => Or::B(b)
+        }
+    }
+
+    /// Convert from `Or<A, B>` to a pair of `Option`s, exactly one of
+    /// which is `Some`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// assert_eq!(x.into_options(), (Some(2), None));
+    ///
+    /// let y: Or<i32, &str> = Or::B("two");
+    /// assert_eq!(y.into_options(), (None, Some("two")));
+    /// ```
+    pub fn into_options(self) -> (Option<A>, Option<B>) {
+        match self {
+            Or::A(a) => (Some(a), None),
+            Or::B(b) => (None, Some(b))
+        }
+    }
+
+    /// Convert from a pair of `Option`s to `Or<A, B>`
+    ///
+    /// Exactly one of the `Option`s must be `Some`. If both or neither
+    /// are, they are returned unchanged as the error.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// assert_eq!(Or::from_options(Some(2), None::<&str>), Ok(Or::A(2)));
+    /// assert_eq!(Or::from_options(None::<i32>, Some("two")), Ok(Or::B("two")));
+    ///
+    /// assert_eq!(Or::from_options(Some(2), Some("two")), Err((Some(2), Some("two"))));
+    /// assert_eq!(Or::from_options(None::<i32>, None::<&str>), Err((None, None)));
+    /// ```
+    pub fn from_options(a: Option<A>, b: Option<B>) -> Result<Self, (Option<A>, Option<B>)> {
+        match (a, b) {
+            (Some(a), None) => Ok(Or::A(a)),
+            (None, Some(b)) => Ok(Or::B(b)),
+            (a, b) => Err((a, b))
+        }
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// let x: Or<i32, &str> = Ok(2).into();
+/// assert_eq!(x, Or::A(2));
+/// ```
+impl<A, B> From<Result<A, B>> for Or<A, B> {
+    fn from(result: Result<A, B>) -> Self {
+        Or::from_result(result)
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// let x: Result<i32, &str> = Or::B("no").into();
+/// assert_eq!(x, Err("no"));
+/// ```
+impl<A, B> From<Or<A, B>> for Result<A, B> {
+    fn from(or: Or<A, B>) -> Self {
+        or.into_result()
+    }
+}
+
+/// `Continue` converts to `A` and `Break` to `B`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::ops::ControlFlow;
+///
+/// let x: Or<i32, &str> = ControlFlow::Continue(2).into();
+/// assert_eq!(x, Or::A(2));
+///
+/// let y: Or<i32, &str> = ControlFlow::Break("stop").into();
+/// assert_eq!(y, Or::B("stop"));
+/// ```
+impl<A, B> From<ControlFlow<B, A>> for Or<A, B> {
+    fn from(flow: ControlFlow<B, A>) -> Self {
+        match flow {
+            ControlFlow::Continue(a) => Or::A(a),
+            ControlFlow::Break(b) => Or::B(b)
+        }
+    }
+}
+
+/// `A` converts to `Continue` and `B` to `Break`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::ops::ControlFlow;
+///
+/// let x: ControlFlow<&str, i32> = Or::A(2).into();
+/// assert_eq!(x, ControlFlow::Continue(2));
+///
+/// let y: ControlFlow<&str, i32> = Or::B("stop").into();
+/// assert_eq!(y, ControlFlow::Break("stop"));
+/// ```
+impl<A, B> From<Or<A, B>> for ControlFlow<B, A> {
+    fn from(or: Or<A, B>) -> Self {
+        match or {
+            Or::A(a) => ControlFlow::Continue(a),
+            Or::B(b) => ControlFlow::Break(b)
+        }
+    }
+}
+
+/// `Ready` converts to `A` and `Pending` to `B`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::task::Poll;
+///
+/// assert_eq!(Or::from(Poll::Ready(2)), Or::A(2));
+/// assert_eq!(Or::from(Poll::Pending::<i32>), Or::B(()));
+/// ```
+impl<T> From<Poll<T>> for Or<T, ()> {
+    fn from(poll: Poll<T>) -> Self {
+        match poll {
+            Poll::Ready(t) => Or::A(t),
+            Poll::Pending => Or::B(())
+        }
+    }
+}
@@ -0,0 +1,297 @@
+//! Type-directed construction and destructuring of `Or`.
+//!
+//! An `Or`, or a nested chain of them such as `Or<A, Or<B, C>>`, can be
+//! treated as a set of alternatives. `Inject` builds one from any of those
+//! alternatives and `Uninject` takes one apart, locating the right slot by
+//! type alone, so callers don't need to know how the alternatives are
+//! ordered or nested.
+//!
+//! The slot is found at compile time through an index type parameter,
+//! which is always inferred; it is only there so that the impls for each
+//! slot don't overlap. If the same type occurs in more than one slot the
+//! index can't be inferred, and the usual methods have to be used instead.
+//!
+//! Building on these, `Embed` moves every alternative of one sum type into
+//! a larger one which has all of its alternatives, and `Reorder` converts
+//! between sum types with the same alternatives in a different order.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use or::Or;
+//! use std::io;
+//! use std::num::ParseIntError;
+//!
+//! type Error = Or<io::Error, Or<ParseIntError, String>>;
+//!
+//! let err = Error::inject("bad input".to_string());
+//! assert_eq!(err.uninject::<String, _>().ok(), Some("bad input".to_string()));
+//!
+//! let err = Error::inject("x".parse::<i32>().unwrap_err());
+//! assert!(err.uninject::<ParseIntError, _>().is_ok());
+//!
+//! let err = Error::inject(io::Error::new(io::ErrorKind::Other, "oh no"));
+//! assert!(err.is_a());
+//! ```
+
+use std::marker::PhantomData;
+
+use Or;
+
+/// Index of the `A` slot of an `Or`
+pub enum AtA {}
+
+/// Index of the `B` slot of an `Or`
+pub enum AtB {}
+
+/// Index of a slot within the `A` of an `Or`, where `I` is its index in `A`
+pub struct InA<I>(PhantomData<I>);
+
+/// Index of a slot within the `B` of an `Or`, where `I` is its index in `B`
+pub struct InB<I>(PhantomData<I>);
+
+/// Index of an embedding which places the whole value in one slot, where
+/// `I` is the index of that slot
+pub struct Whole<I>(PhantomData<I>);
+
+/// Index of an embedding of an `Or` which places each side separately,
+/// where `IA` and `IB` are the indices of the embeddings of each side
+pub struct Each<IA, IB>(PhantomData<(IA, IB)>);
+
+/// Index of an embedding of an `OrN` through its nested `Or` chain, where
+/// `I` is the index of the embedding of that chain
+pub struct Nested<I>(PhantomData<I>);
+
+/// A sum type which can be built from a `T` found at index `I`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no alternative of type `{T}`",
+    label = "`{T}` is not an alternative of `{Self}`"
+)]
+pub trait Inject<T, I> {
+    /// Builds `Self` from a `T`, placing it in the slot at index `I`
+    fn inject(value: T) -> Self;
+}
+
+/// A sum type which may hold a `T` at index `I`
+pub trait Uninject<T, I>: Sized {
+    /// The sum of all other alternatives once `T` is taken out
+    type Remainder;
+
+    /// Returns the `T` if present, or else whichever other alternative
+    /// is present
+    fn uninject(self) -> Result<T, Self::Remainder>;
+}
+
+/// A sum type whose alternatives can all be placed in the sum type
+/// `Target`, as directed by the index `I`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be embedded in `{Target}`",
+    label = "`{Target}` doesn't have every alternative of `{Self}`"
+)]
+pub trait Embed<Target, I> {
+    /// Converts `self` to `Target`, keeping whichever alternative is present
+    fn embed(self) -> Target;
+}
+
+/// A sum type with the same alternatives as `Target`, possibly in a
+/// different order, as directed by the index `I`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be reordered into `{Target}`",
+    label = "`{Target}` doesn't have exactly the alternatives of `{Self}`"
+)]
+pub trait Reorder<Target, I> {
+    /// Converts `self` to `Target`, keeping whichever alternative is present
+    fn reorder(self) -> Target;
+}
+
+impl<A, B> Inject<A, AtA> for Or<A, B> {
+    fn inject(a: A) -> Self { Or::A(a) }
+}
+
+impl<A, B> Inject<B, AtB> for Or<A, B> {
+    fn inject(b: B) -> Self { Or::B(b) }
+}
+
+impl<A, B, T, I> Inject<T, InA<I>> for Or<A, B> where A: Inject<T, I> {
+    fn inject(value: T) -> Self { Or::A(A::inject(value)) }
+}
+
+impl<A, B, T, I> Inject<T, InB<I>> for Or<A, B> where B: Inject<T, I> {
+    fn inject(value: T) -> Self { Or::B(B::inject(value)) }
+}
+
+impl<A, B> Uninject<A, AtA> for Or<A, B> {
+    type Remainder = B;
+
+    fn uninject(self) -> Result<A, B> {
+        match self {
+            Or::A(a) => Ok(a),
+            Or::B(b) => Err(b)
+        }
+    }
+}
+
+impl<A, B> Uninject<B, AtB> for Or<A, B> {
+    type Remainder = A;
+
+    fn uninject(self) -> Result<B, A> {
+        match self {
+            Or::A(a) => Err(a),
+            Or::B(b) => Ok(b)
+        }
+    }
+}
+
+impl<A, B, T, I> Uninject<T, InA<I>> for Or<A, B> where A: Uninject<T, I> {
+    type Remainder = Or<A::Remainder, B>;
+
+    fn uninject(self) -> Result<T, Self::Remainder> {
+        match self {
+            Or::A(a) => a.uninject().map_err(Or::A),
+            Or::B(b) => Err(Or::B(b))
+        }
+    }
+}
+
+impl<A, B, T, I> Uninject<T, InB<I>> for Or<A, B> where B: Uninject<T, I> {
+    type Remainder = Or<A, B::Remainder>;
+
+    fn uninject(self) -> Result<T, Self::Remainder> {
+        match self {
+            Or::A(a) => Err(Or::A(a)),
+            Or::B(b) => b.uninject().map_err(Or::B)
+        }
+    }
+}
+
+impl<T, Target, I> Embed<Target, Whole<I>> for T where Target: Inject<T, I> {
+    fn embed(self) -> Target { Target::inject(self) }
+}
+
+impl<A, B, Target, IA, IB> Embed<Target, Each<IA, IB>> for Or<A, B>
+where A: Embed<Target, IA>, B: Embed<Target, IB> {
+    fn embed(self) -> Target {
+        match self {
+            Or::A(a) => a.embed(),
+            Or::B(b) => b.embed()
+        }
+    }
+}
+
+impl<S, Target, I, J> Reorder<Target, (I, J)> for S
+where S: Embed<Target, I>, Target: Embed<S, J> {
+    fn reorder(self) -> Target { self.embed() }
+}
+
+impl<A, B> Or<A, B> {
+    /// Builds an `Or` from any of its alternatives, found by type
+    ///
+    /// The alternative may be nested arbitrarily deeply in other `Or`s.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// type Nested = Or<Or<i32, bool>, Or<char, &'static str>>;
+    ///
+    /// assert_eq!(Nested::inject(true), Or::A(Or::B(true)));
+    /// assert_eq!(Nested::inject('c'), Or::B(Or::A('c')));
+    /// ```
+    pub fn inject<T, I>(value: T) -> Self
+    where Self: Inject<T, I> {
+        <Self as Inject<T, I>>::inject(value)
+    }
+
+    /// Takes a `T` out of an `Or`, wherever it is, or returns the rest
+    ///
+    /// The remainder has the same shape as `self`, but with the slot
+    /// holding `T` removed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, Or<bool, char>> = Or::B(Or::A(true));
+    /// assert_eq!(x.uninject::<bool, _>(), Ok(true));
+    ///
+    /// let y: Or<i32, Or<bool, char>> = Or::B(Or::B('c'));
+    /// let rest: Result<bool, Or<i32, char>> = y.uninject();
+    /// assert_eq!(rest, Err(Or::B('c')));
+    /// ```
+    pub fn uninject<T, I>(self) -> Result<T, <Self as Uninject<T, I>>::Remainder>
+    where Self: Uninject<T, I> {
+        <Self as Uninject<T, I>>::uninject(self)
+    }
+
+    /// Converts an `Or` to a larger sum type with all of its alternatives
+    ///
+    /// The alternatives of `self`, including those of any `Or`s nested in
+    /// it, are each found by type in `Target`, which may order and nest
+    /// them differently, and may be an `OrN`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, Or3};
+    /// let x: Or<i32, bool> = Or::B(true);
+    /// assert_eq!(x.embed::<Or3<bool, char, i32>, _>(), Or3::A(true));
+    ///
+    /// let x: Or<Or<i32, bool>, char> = Or::A(Or::A(1));
+    /// let y: Or<char, Or<&str, Or<bool, i32>>> = x.embed();
+    /// assert_eq!(y, Or::B(Or::B(Or::B(1))));
+    /// ```
+    ///
+    /// Every alternative must be present in the target:
+    ///
+    /// ```rust,compile_fail
+    /// # use or::{Or, Or3};
+    /// let x: Or<i32, bool> = Or::B(true);
+    /// x.embed::<Or3<bool, char, u8>, _>();
+    /// ```
+    pub fn embed<Target, I>(self) -> Target
+    where Self: Embed<Target, I> {
+        <Self as Embed<Target, I>>::embed(self)
+    }
+
+    /// Converts an `Or` to a sum type with the same alternatives, in any
+    /// order or nesting
+    ///
+    /// For two alternatives this is the same as `swap`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, Or3};
+    /// let x: Or<i32, bool> = Or::A(1);
+    /// assert_eq!(x.clone().reorder::<Or<bool, i32>, _>(), x.swap());
+    ///
+    /// let x: Or<i32, Or<bool, char>> = Or::B(Or::B('c'));
+    /// let y: Or3<char, i32, bool> = x.clone().reorder();
+    /// assert_eq!(y, Or3::A('c'));
+    ///
+    /// // `OrN`s can be reordered through the trait.
+    /// use or::coproduct::Reorder;
+    /// let back: Or<i32, Or<bool, char>> = y.reorder();
+    /// assert_eq!(back, x);
+    /// ```
+    ///
+    /// The target can't leave out any alternative:
+    ///
+    /// ```rust,compile_fail
+    /// # use or::{Or, Or3};
+    /// let x: Or<i32, Or<bool, char>> = Or::B(Or::B('c'));
+    /// x.reorder::<Or<char, i32>, _>();
+    /// ```
+    ///
+    /// Nor can it have any extra ones:
+    ///
+    /// ```rust,compile_fail
+    /// # use or::{Or, Or3};
+    /// let x: Or<i32, bool> = Or::A(1);
+    /// x.reorder::<Or3<bool, i32, char>, _>();
+    /// ```
+    pub fn reorder<Target, I>(self) -> Target
+    where Self: Reorder<Target, I> {
+        <Self as Reorder<Target, I>>::reorder(self)
+    }
+}
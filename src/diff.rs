@@ -0,0 +1,398 @@
+//! Minimal edit scripts between two slices.
+//!
+//! `diff` computes the shortest sequence of removals and additions that
+//! turns one slice into another, using the linear space variant of Myers'
+//! algorithm. The edit script is a sequence of `Or`s, each either a run of
+//! `Unchanged` elements or a single change, itself an `Or` of a `Removed`
+//! or an `Added` element.
+//!
+//! ## Example
+//!
+//! ```rust
+//! # use or::Or;
+//! use or::diff::{self, Added, Removed, Unchanged};
+//!
+//! let old = ["a", "b", "c", "d"];
+//! let new = ["a", "c", "d", "e"];
+//!
+//! let script = diff::diff(&old, &new);
+//!
+//! assert_eq!(script, vec![Or::A(Unchanged(&old[..1])),
+//!                         Or::B(Or::A(Removed(&"b"))),
+//!                         Or::A(Unchanged(&old[2..])),
+//!                         Or::B(Or::B(Added(&"e")))]);
+//!
+//! assert_eq!(diff::apply(&old, &script), Some(new.to_vec()));
+//! ```
+
+use std::iter;
+
+use {Or, These};
+
+/// An element present only in the old slice
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Removed<T>(pub T);
+
+/// An element present only in the new slice
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Added<T>(pub T);
+
+/// A run of elements present in both slices
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Unchanged<T>(pub T);
+
+/// A single removal or addition
+pub type Change<'a, T> = Or<Removed<&'a T>, Added<&'a T>>;
+
+/// One step of an edit script, as returned by `diff`
+pub type Edit<'a, T> = Or<Unchanged<&'a [T]>, Change<'a, T>>;
+
+/// One step of an edit script with substitutions paired up, as returned
+/// by `diff_paired`
+pub type PairedEdit<'a, T> = Or<Unchanged<&'a [T]>, These<Removed<&'a T>, Added<&'a T>>>;
+
+// A single step through both slices.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Step {
+    Keep,
+    Remove,
+    Add
+}
+
+/// Computes a minimal edit script from `old` to `new`
+///
+/// The script has as few changes as possible, and every run of unchanged
+/// elements is as long as possible. Within a run of changes, removals come
+/// before additions.
+///
+/// For slices of lengths N and M with D changes between them, this takes
+/// O((N + M) * D) time and O(N + M) space.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use or::diff::{self, Added, Removed, Unchanged};
+///
+/// let old = [1, 2, 3];
+/// let new = [1, 4, 3];
+///
+/// assert_eq!(diff::diff(&old, &new),
+///            vec![Or::A(Unchanged(&old[..1])),
+///                 Or::B(Or::A(Removed(&2))),
+///                 Or::B(Or::B(Added(&4))),
+///                 Or::A(Unchanged(&old[2..]))]);
+/// ```
+pub fn diff<'a, T>(old: &'a [T], new: &'a [T]) -> Vec<Edit<'a, T>>
+where T: PartialEq {
+    let mut script = Vec::new();
+    let (mut x, mut y) = (0, 0);
+    let mut unchanged = 0;
+
+    for step in shortest_path(old, new) {
+        if let Step::Keep = step {
+            unchanged += 1;
+            x += 1;
+            y += 1;
+            continue;
+        }
+
+        if unchanged > 0 {
+            script.push(Or::A(Unchanged(&old[x - unchanged..x])));
+            unchanged = 0;
+        }
+
+        match step {
+            Step::Remove => { script.push(Or::B(Or::A(Removed(&old[x])))); x += 1 },
+            Step::Add => { script.push(Or::B(Or::B(Added(&new[y])))); y += 1 },
+            Step::Keep => unreachable!()
+        }
+    }
+
+    if unchanged > 0 {
+        script.push(Or::A(Unchanged(&old[x - unchanged..x])));
+    }
+
+    script
+}
+
+/// Computes a minimal edit script from `old` to `new`, pairing removals
+/// with additions as substitutions
+///
+/// Within each run of changes, removals and additions are paired up in
+/// order as `Both`. Whichever are left over are kept as `This` for a
+/// removal or `That` for an addition.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::{Or, These};
+/// use or::diff::{self, Added, Removed, Unchanged};
+///
+/// let old = ["a", "b", "c", "d"];
+/// let new = ["a", "x", "y", "d"];
+///
+/// assert_eq!(diff::diff_paired(&old, &new),
+///            vec![Or::A(Unchanged(&old[..1])),
+///                 Or::B(These::Both(Removed(&"b"), Added(&"x"))),
+///                 Or::B(These::Both(Removed(&"c"), Added(&"y"))),
+///                 Or::A(Unchanged(&old[3..]))]);
+///
+/// let new = ["a", "x", "d"];
+///
+/// assert_eq!(diff::diff_paired(&old, &new),
+///            vec![Or::A(Unchanged(&old[..1])),
+///                 Or::B(These::Both(Removed(&"b"), Added(&"x"))),
+///                 Or::B(These::This(Removed(&"c"))),
+///                 Or::A(Unchanged(&old[3..]))]);
+/// ```
+pub fn diff_paired<'a, T>(old: &'a [T], new: &'a [T]) -> Vec<PairedEdit<'a, T>>
+where T: PartialEq {
+    fn flush<'a, T>(script: &mut Vec<PairedEdit<'a, T>>,
+                    removed: &mut Vec<Removed<&'a T>>, added: &mut Vec<Added<&'a T>>) {
+        let mut added = added.drain(..);
+
+        for removed in removed.drain(..) {
+            script.push(Or::B(match added.next() {
+                Some(added) => These::Both(removed, added),
+                None => These::This(removed)
+            }));
+        }
+
+        script.extend(added.map(|added| Or::B(These::That(added))));
+    }
+
+    let mut script = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    for edit in diff(old, new) {
+        match edit {
+            Or::A(unchanged) => {
+                flush(&mut script, &mut removed, &mut added);
+                script.push(Or::A(unchanged));
+            },
+            Or::B(Or::A(r)) => removed.push(r),
+            Or::B(Or::B(a)) => added.push(a)
+        }
+    }
+
+    flush(&mut script, &mut removed, &mut added);
+    script
+}
+
+/// Applies an edit script to `old`, returning the new elements
+///
+/// Returns `None` if the script does not match `old`, because an unchanged
+/// or removed element differs from `old`, or the script does not cover
+/// exactly all of `old`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use or::diff::{self, Added, Removed, Unchanged};
+///
+/// let script = vec![Or::A(Unchanged(&[1, 2][..])),
+///                   Or::B(Or::A(Removed(&3))),
+///                   Or::B(Or::B(Added(&4)))];
+///
+/// assert_eq!(diff::apply(&[1, 2, 3], &script), Some(vec![1, 2, 4]));
+/// assert_eq!(diff::apply(&[1, 2, 5], &script), None);
+/// assert_eq!(diff::apply(&[1, 2, 3, 4], &script), None);
+/// ```
+pub fn apply<T>(old: &[T], script: &[Edit<T>]) -> Option<Vec<T>>
+where T: PartialEq + Clone {
+    let mut old = old;
+    let mut new = Vec::new();
+
+    for edit in script {
+        match *edit {
+            Or::A(Unchanged(run)) => {
+                if !old.starts_with(run) { return None }
+                new.extend_from_slice(run);
+                old = &old[run.len()..];
+            },
+            Or::B(Or::A(Removed(removed))) => match old.split_first() {
+                Some((first, rest)) if first == removed => old = rest,
+                _ => return None
+            },
+            Or::B(Or::B(Added(added))) => new.push(added.clone())
+        }
+    }
+
+    if old.is_empty() { Some(new) } else { None }
+}
+
+// The furthest x reached on each diagonal k = x - y by the searches from
+// the start and from the end of the edit graph, offset so that every
+// diagonal searched can be indexed. Shared by every subproblem, which is
+// what keeps the space linear.
+struct Frontiers {
+    forward: Vec<isize>,
+    backward: Vec<isize>,
+    offset: isize
+}
+
+// Finds the shortest path through the edit graph of `old` and `new` using
+// the linear space variant of Myers' algorithm, returning the steps from
+// the start.
+fn shortest_path<T>(old: &[T], new: &[T]) -> Vec<Step>
+where T: PartialEq {
+    let offset = (old.len() + new.len()) as isize / 2 + 2;
+    let mut frontiers = Frontiers {
+        forward: vec![0; 2 * offset as usize + 1],
+        backward: vec![0; 2 * offset as usize + 1],
+        offset
+    };
+
+    let mut steps = Vec::with_capacity(old.len().max(new.len()));
+    find_path(old, new, &mut frontiers, &mut steps);
+
+    // The halves of the path are found separately, so put removals before
+    // additions within each run of changes.
+    for run in steps.split_mut(|step| matches!(*step, Step::Keep)) {
+        run.sort_unstable();
+    }
+
+    steps
+}
+
+// Appends the steps of a shortest path through the edit graph of `old` and
+// `new` to `steps`, by splitting it in two at a point found by
+// `middle_snake` and finding the path through each half.
+fn find_path<T>(old: &[T], new: &[T], frontiers: &mut Frontiers, steps: &mut Vec<Step>)
+where T: PartialEq {
+    let prefix = old.iter().zip(new).take_while(|&(a, b)| a == b).count();
+    let (old, new) = (&old[prefix..], &new[prefix..]);
+    let suffix = old.iter().rev().zip(new.iter().rev()).take_while(|&(a, b)| a == b).count();
+    let (old, new) = (&old[..old.len() - suffix], &new[..new.len() - suffix]);
+
+    steps.extend(iter::repeat_n(Step::Keep, prefix));
+
+    if old.is_empty() {
+        steps.extend(iter::repeat_n(Step::Add, new.len()));
+    } else if new.is_empty() {
+        steps.extend(iter::repeat_n(Step::Remove, old.len()));
+    } else {
+        let (x, y) = middle_snake(old, new, frontiers);
+        find_path(&old[..x], &new[..y], frontiers, steps);
+        find_path(&old[x..], &new[y..], frontiers, steps);
+    }
+
+    steps.extend(iter::repeat_n(Step::Keep, suffix));
+}
+
+// Finds a point partway along a shortest path through the edit graph of
+// `old` and `new`, by searching from both ends at once until the searches
+// overlap. The slices must differ in their first and last elements, so
+// that the point is at neither end.
+fn middle_snake<T>(old: &[T], new: &[T], frontiers: &mut Frontiers) -> (usize, usize)
+where T: PartialEq {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+
+    // Diagonal k from the start is diagonal delta - k from the end, and the
+    // searches first overlap from the start if delta is odd.
+    let delta = n - m;
+    let odd = delta % 2 != 0;
+
+    let Frontiers { ref mut forward, ref mut backward, offset } = *frontiers;
+    let at = |k: isize| (k + offset) as usize;
+
+    forward[at(1)] = 0;
+    backward[at(1)] = 0;
+
+    for d in 0..(n + m + 1) / 2 + 1 {
+        for k in (-d..d + 1).step_by(2) {
+            let start_x = if k == -d || (k != d && forward[at(k - 1)] < forward[at(k + 1)]) {
+                forward[at(k + 1)]
+            } else {
+                forward[at(k - 1)] + 1
+            };
+            let start_y = start_x - k;
+
+            let (mut x, mut y) = (start_x, start_y);
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            forward[at(k)] = x;
+
+            if odd && (delta - k).abs() < d && x + backward[at(delta - k)] >= n {
+                return (start_x as usize, start_y as usize);
+            }
+        }
+
+        for k in (-d..d + 1).step_by(2) {
+            let start_x = if k == -d || (k != d && backward[at(k - 1)] < backward[at(k + 1)]) {
+                backward[at(k + 1)]
+            } else {
+                backward[at(k - 1)] + 1
+            };
+            let start_y = start_x - k;
+
+            let (mut x, mut y) = (start_x, start_y);
+            while x < n && y < m && old[(n - x - 1) as usize] == new[(m - y - 1) as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            backward[at(k)] = x;
+
+            if !odd && (delta - k).abs() <= d && x + forward[at(delta - k)] >= n {
+                return ((n - start_x) as usize, (m - start_y) as usize);
+            }
+        }
+    }
+
+    unreachable!("the searches from each end always overlap")
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use super::{apply, diff};
+
+    // The length of the longest common subsequence of `a` and `b`.
+    fn lcs(a: &[u8], b: &[u8]) -> usize {
+        let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                table[i + 1][j + 1] = if a[i] == b[j] { table[i][j] + 1 }
+                                      else { table[i][j + 1].max(table[i + 1][j]) };
+            }
+        }
+        table[a.len()][b.len()]
+    }
+
+    // Also checks small elements, so that the slices have plenty in common.
+    fn with_small(old: Vec<u8>, new: Vec<u8>) -> Vec<(Vec<u8>, Vec<u8>)> {
+        let small = |v: &[u8]| v.iter().map(|x| x % 4).collect::<Vec<_>>();
+        let (small_old, small_new) = (small(&old), small(&new));
+        vec![(old, new), (small_old, small_new)]
+    }
+
+    #[test]
+    fn diff_applies() {
+        fn prop(old: Vec<u8>, new: Vec<u8>) -> bool {
+            with_small(old, new).into_iter()
+                .all(|(old, new)| apply(&old, &diff(&old, &new)) == Some(new))
+        }
+
+        quickcheck(prop as fn(Vec<u8>, Vec<u8>) -> bool);
+    }
+
+    #[test]
+    fn diff_is_minimal() {
+        fn prop(old: Vec<u8>, new: Vec<u8>) -> bool {
+            with_small(old, new).into_iter().all(|(old, new)| {
+                let changes = diff(&old, &new).iter().filter(|edit| edit.is_b()).count();
+                changes == old.len() + new.len() - 2 * lcs(&old, &new)
+            })
+        }
+
+        quickcheck(prop as fn(Vec<u8>, Vec<u8>) -> bool);
+    }
+}
@@ -0,0 +1,83 @@
+//! `Display` and `Error` support for `Or`.
+
+use std::error::Error;
+use std::fmt;
+
+use Or;
+
+/// Displays whichever side is present.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// let x: Or<i32, &str> = Or::A(5);
+/// assert_eq!(x.to_string(), "5");
+///
+/// let y: Or<i32, &str> = Or::B("five");
+/// assert_eq!(y.to_string(), "five");
+/// ```
+impl<A, B> fmt::Display for Or<A, B>
+where A: fmt::Display, B: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for_both!(*self, ref inner => inner.fmt(f))
+    }
+}
+
+/// `Or` is an error if both sides are errors.
+///
+/// The `Or` is transparent: it displays as, and has the same `source` as,
+/// whichever error is present, so walking the chain of an `Or` gives the
+/// same errors as walking the chain of its inner error. Through the
+/// standard library, `?` can also convert an `Or` of errors into a
+/// `Box<dyn Error + Send + Sync>`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::error::Error;
+/// use std::{fmt, io};
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug)]
+/// struct ConfigError(io::Error);
+///
+/// impl fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         f.write_str("could not read config")
+///     }
+/// }
+///
+/// impl Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> { Some(&self.0) }
+/// }
+///
+/// fn chain(err: &dyn Error) -> Vec<String> {
+///     let mut chain = vec![err.to_string()];
+///     let mut source = err.source();
+///     while let Some(err) = source {
+///         chain.push(err.to_string());
+///         source = err.source();
+///     }
+///     chain
+/// }
+///
+/// fn load() -> Result<i32, Box<dyn Error + Send + Sync>> {
+///     let err = ConfigError(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+///     let result: Result<i32, Or<ConfigError, ParseIntError>> = Err(Or::A(err));
+///     Ok(result?)
+/// }
+///
+/// let err = load().unwrap_err();
+/// let inner = ConfigError(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+///
+/// assert_eq!(chain(&*err), chain(&inner));
+/// assert_eq!(chain(&*err), vec!["could not read config", "no such file"]);
+/// ```
+impl<A, B> Error for Or<A, B>
+where A: Error, B: Error {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        for_both!(*self, ref inner => inner.source())
+    }
+}
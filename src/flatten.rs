@@ -0,0 +1,329 @@
+//! Flattening nested `Or` trees into a single chain.
+//!
+//! Any tree of nested `Or`s, such as `Or<Or<A, B>, Or<C, D>>`, has a flat
+//! equivalent holding the same alternatives in the same order: the
+//! right-nested chain `Or<A, Or<B, Or<C, D>>>`. `Or::flatten_or` and
+//! `Or::unflatten_or` convert between the two through the `Flatten` trait,
+//! and the flat chain can then be converted to the matching `OrN` enum
+//! with `into`.
+//!
+//! The flat type is computed entirely at compile time, and converting
+//! only moves the value into its new place.
+//!
+//! Telling an `Or` to be flattened apart from any other alternative needs
+//! the alternatives to be marked as `Leaf`s. This crate marks the
+//! primitive types, tuples, the common standard library collections,
+//! pointers and error types, and the types it defines itself. A type
+//! defined in your own crate can be marked with an empty impl:
+//!
+//! ```rust
+//! # use or::Or;
+//! use or::flatten::Leaf;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct ConfigError;
+//!
+//! impl Leaf for ConfigError {}
+//!
+//! let x: Or<Or<ConfigError, i32>, bool> = Or::A(Or::A(ConfigError));
+//! let flat: Or<ConfigError, Or<i32, bool>> = x.flatten_or();
+//! assert_eq!(flat, Or::A(ConfigError));
+//! ```
+//!
+//! The orphan rule doesn't allow marking a type from another crate, such
+//! as another library's error type. For trees holding such types, name the
+//! flat type and use `Or::embed` from the `coproduct` module instead, which
+//! finds each alternative by type and needs no `Leaf` impls:
+//!
+//! ```rust
+//! # extern crate or;
+//! # extern crate serde_json;
+//! # use or::{Or, Or3};
+//! use std::{io, num};
+//!
+//! type Nested = Or<Or<serde_json::Error, io::Error>, num::ParseIntError>;
+//!
+//! # fn main() {
+//! let x: Nested = Or::B("x".parse::<u8>().unwrap_err());
+//!
+//! let flat: Or<serde_json::Error, Or<io::Error, num::ParseIntError>> = x.embed();
+//! assert!(flat.b().and_then(Or::b).is_some());
+//!
+//! let x: Nested = Or::A(Or::B(io::Error::new(io::ErrorKind::Other, "oh no")));
+//! let flat: Or3<serde_json::Error, io::Error, num::ParseIntError> = x.embed();
+//! assert!(flat.is_b());
+//! # }
+//! ```
+//!
+//! This needs every alternative to have a distinct type. Alternatively,
+//! wrap the foreign type in a newtype of your own and mark that:
+//!
+//! ```rust
+//! # extern crate or;
+//! # extern crate serde_json;
+//! # use or::Or;
+//! use or::flatten::Leaf;
+//! use std::io;
+//!
+//! #[derive(Debug)]
+//! struct JsonError(serde_json::Error);
+//!
+//! impl Leaf for JsonError {}
+//!
+//! fn parse(s: &str) -> Result<u32, Or<JsonError, io::Error>> {
+//!     serde_json::from_str(s).map_err(|e| Or::A(JsonError(e)))
+//! }
+//!
+//! # fn main() {
+//! let x: Or<Or<JsonError, io::Error>, bool> = Or::A(parse("nope").unwrap_err());
+//! let flat: Or<JsonError, Or<io::Error, bool>> = x.flatten_or();
+//! assert!(flat.is_a());
+//! # }
+//! ```
+//!
+//! `Or3` through `Or12` are `Leaf`s themselves, so one nested in an `Or` is
+//! kept whole rather than flattened. Convert it to its nested `Or` chain
+//! with `into` first to flatten its alternatives too:
+//!
+//! ```rust
+//! # use or::{Or, Or3};
+//! let x: Or<Or3<i32, bool, char>, String> = Or::A(Or3::C('c'));
+//! let kept: Or<Or3<i32, bool, char>, String> = x.clone().flatten_or();
+//! assert_eq!(kept, x);
+//!
+//! let x: Or<Or<i32, Or<bool, char>>, String> = x.map_a(Into::into);
+//! let flat: Or<i32, Or<bool, Or<char, String>>> = x.flatten_or();
+//! assert_eq!(flat, Or::B(Or::B(Or::A('c'))));
+//! ```
+//!
+//! Unmarked alternatives can't be flattened:
+//!
+//! ```rust,compile_fail
+//! # use or::Or;
+//! struct ConfigError;
+//!
+//! let x: Or<Or<ConfigError, i32>, bool> = Or::A(Or::A(ConfigError));
+//! x.flatten_or();
+//! ```
+
+use std::{array, char, convert, env, ffi, fmt, io, net, num, str, string, time};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use {Or, ParseError, These};
+
+/// A type which is a single alternative of a flattened `Or`, rather than
+/// an `Or` to be flattened itself
+pub trait Leaf {}
+
+/// A tree of nested `Or`s, or a single `Leaf`, which can be converted to
+/// and from a flat right-nested chain
+pub trait Flatten: Sized {
+    /// The flat right-nested chain of the alternatives of `Self`
+    type Output;
+
+    /// Converts `Self` to its flat chain
+    fn flatten(self) -> Self::Output;
+
+    /// Converts a flat chain back to the shape of `Self`
+    fn unflatten(flat: Self::Output) -> Self;
+}
+
+/// A flat chain of alternatives which can have the alternatives of
+/// another chain `R` appended to it
+pub trait Append<R>: Sized {
+    /// The chain of the alternatives of `Self` followed by those of `R`
+    type Output;
+
+    /// Places a value of `Self` in the appended chain
+    fn append_left(self) -> Self::Output;
+
+    /// Places a value of `R` in the appended chain
+    fn append_right(right: R) -> Self::Output;
+
+    /// Splits the appended chain back into `Self` and `R`
+    fn split(appended: Self::Output) -> Or<Self, R>;
+}
+
+impl<T> Flatten for T where T: Leaf {
+    type Output = T;
+
+    fn flatten(self) -> T { self }
+
+    fn unflatten(flat: T) -> T { flat }
+}
+
+impl<A, B> Flatten for Or<A, B>
+where A: Flatten, B: Flatten, A::Output: Append<B::Output> {
+    type Output = <A::Output as Append<B::Output>>::Output;
+
+    fn flatten(self) -> Self::Output {
+        match self {
+            Or::A(a) => a.flatten().append_left(),
+            Or::B(b) => A::Output::append_right(b.flatten())
+        }
+    }
+
+    fn unflatten(flat: Self::Output) -> Self {
+        match A::Output::split(flat) {
+            Or::A(a) => Or::A(A::unflatten(a)),
+            Or::B(b) => Or::B(B::unflatten(b))
+        }
+    }
+}
+
+impl<T, R> Append<R> for T where T: Leaf {
+    type Output = Or<T, R>;
+
+    fn append_left(self) -> Or<T, R> { Or::A(self) }
+
+    fn append_right(right: R) -> Or<T, R> { Or::B(right) }
+
+    fn split(appended: Or<T, R>) -> Or<T, R> { appended }
+}
+
+impl<H, T, R> Append<R> for Or<H, T> where T: Append<R> {
+    type Output = Or<H, T::Output>;
+
+    fn append_left(self) -> Self::Output {
+        match self {
+            Or::A(h) => Or::A(h),
+            Or::B(t) => Or::B(t.append_left())
+        }
+    }
+
+    fn append_right(right: R) -> Self::Output {
+        Or::B(T::append_right(right))
+    }
+
+    fn split(appended: Self::Output) -> Or<Self, R> {
+        match appended {
+            Or::A(h) => Or::A(Or::A(h)),
+            Or::B(rest) => match T::split(rest) {
+                Or::A(t) => Or::A(Or::B(t)),
+                Or::B(r) => Or::B(r)
+            }
+        }
+    }
+}
+
+impl<A, B> Or<A, B> {
+    /// Flattens a tree of nested `Or`s into a right-nested chain
+    ///
+    /// The alternatives keep their order, and any that are not themselves
+    /// `Or`s must be `Leaf`s.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, Or4};
+    /// let x: Or<Or<i32, bool>, Or<char, String>> = Or::B(Or::A('c'));
+    ///
+    /// // The flat type is inferred, the annotation only checks it.
+    /// let flat: Or<i32, Or<bool, Or<char, String>>> = x.flatten_or();
+    /// assert_eq!(flat, Or::B(Or::B(Or::A('c'))));
+    ///
+    /// let flat: Or4<_, _, _, _> = flat.into();
+    /// assert_eq!(flat, Or4::C('c'));
+    ///
+    /// let x: Or<Or<Or<i32, bool>, char>, String> = Or::B("s".to_string());
+    /// let flat: Or<i32, Or<bool, Or<char, String>>> = x.flatten_or();
+    /// assert_eq!(flat, Or::B(Or::B(Or::B("s".to_string()))));
+    /// ```
+    ///
+    /// The chain is fully flattened, so it can't keep any nesting:
+    ///
+    /// ```rust,compile_fail
+    /// # use or::Or;
+    /// let x: Or<Or<i32, bool>, char> = Or::A(Or::A(1));
+    /// let flat: Or<Or<i32, bool>, char> = x.flatten_or();
+    /// ```
+    ///
+    /// This is named so as not to hide `Iterator::flatten`, which still
+    /// flattens the items of an `Or` of iterators:
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<Box<dyn Iterator<Item = Vec<i32>>>, Box<dyn Iterator<Item = Vec<i32>>>> =
+    ///     Or::A(Box::new(vec![vec![1, 2], vec![3]].into_iter()));
+    /// assert_eq!(x.flatten().count(), 3);
+    /// ```
+    pub fn flatten_or(self) -> <Self as Flatten>::Output
+    where Self: Flatten {
+        Flatten::flatten(self)
+    }
+
+    /// Rebuilds a tree of nested `Or`s from its flattened chain
+    ///
+    /// This is the inverse of `flatten_or`, with the shape to rebuild given
+    /// by the type of `Self`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, Or4};
+    /// type Nested = Or<Or<i32, bool>, Or<char, String>>;
+    ///
+    /// let flat: Or<i32, Or<bool, Or<char, String>>> = Or4::B(true).into();
+    /// assert_eq!(Nested::unflatten_or(flat), Or::A(Or::B(true)));
+    ///
+    /// let x: Nested = Or::B(Or::B("s".to_string()));
+    /// assert_eq!(Nested::unflatten_or(x.clone().flatten_or()), x);
+    /// ```
+    pub fn unflatten_or(flat: <Self as Flatten>::Output) -> Self
+    where Self: Flatten {
+        <Self as Flatten>::unflatten(flat)
+    }
+}
+
+macro_rules! leaf {
+    ($($ty:ty),*) => { $(impl Leaf for $ty {})* };
+}
+
+macro_rules! generic_leaf {
+    ($($ty:ident<$($param:ident),+>),*) => { $(impl<$($param),+> Leaf for $ty<$($param),+> {})* };
+}
+
+macro_rules! tuple_leaf {
+    ($($param:ident),+) => { impl<$($param),+> Leaf for ($($param,)+) {} };
+}
+
+leaf!(bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+      f32, f64, (), String, PathBuf);
+
+leaf!(OsString, Duration, Instant, SystemTime);
+
+leaf!(array::TryFromSliceError, char::CharTryFromError, char::ParseCharError,
+      char::TryFromCharError, convert::Infallible, env::VarError,
+      ffi::FromBytesWithNulError, ffi::IntoStringError, ffi::NulError, fmt::Error,
+      io::Error, net::AddrParseError, num::ParseFloatError, num::ParseIntError,
+      num::TryFromIntError, str::ParseBoolError, str::Utf8Error,
+      string::FromUtf16Error, string::FromUtf8Error, time::SystemTimeError);
+
+generic_leaf!(Option<T>, Result<T, E>, Vec<T>, VecDeque<T>, BTreeSet<T>, HashSet<T, S>,
+              BTreeMap<K, V>, HashMap<K, V, S>, These<A, B>, ParseError<A, B>);
+
+tuple_leaf!(A);
+tuple_leaf!(A, B);
+tuple_leaf!(A, B, C);
+tuple_leaf!(A, B, C, D);
+tuple_leaf!(A, B, C, D, E);
+tuple_leaf!(A, B, C, D, E, F);
+tuple_leaf!(A, B, C, D, E, F, G);
+tuple_leaf!(A, B, C, D, E, F, G, H);
+tuple_leaf!(A, B, C, D, E, F, G, H, I);
+tuple_leaf!(A, B, C, D, E, F, G, H, I, J);
+tuple_leaf!(A, B, C, D, E, F, G, H, I, J, K);
+tuple_leaf!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+impl<T: ?Sized> Leaf for &T {}
+impl<T: ?Sized> Leaf for &mut T {}
+impl<T: ?Sized> Leaf for Box<T> {}
+impl<T: ?Sized> Leaf for Rc<T> {}
+impl<T: ?Sized> Leaf for Arc<T> {}
+impl<'a, T: ?Sized + ToOwned> Leaf for Cow<'a, T> {}
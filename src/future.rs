@@ -0,0 +1,97 @@
+//! Future support for `Or`.
+//!
+//! `Or` of two futures with the same output is itself a future, and
+//! `Or::factor_future` covers futures with different outputs.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use Or;
+
+/// `Or` is a future if both sides are futures with the same `Output`.
+///
+/// Neither side needs to be `Unpin`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::future::{self, Future};
+/// use std::pin::pin;
+/// use std::task::{Context, Poll, Waker};
+///
+/// # use std::marker::PhantomPinned;
+/// # struct Pinned(i32, PhantomPinned);
+/// # impl Future for Pinned {
+/// #     type Output = i32;
+/// #     fn poll(self: std::pin::Pin<&mut Self>, _: &mut Context) -> Poll<i32> {
+/// #         Poll::Ready(self.0)
+/// #     }
+/// # }
+/// # fn pinned(x: i32) -> Pinned { Pinned(x, PhantomPinned) }
+/// let mut cx = Context::from_waker(Waker::noop());
+///
+/// // `pinned` returns a future which is not `Unpin`.
+/// let x: Or<_, future::Ready<i32>> = Or::A(pinned(1));
+/// assert_eq!(pin!(x).poll(&mut cx), Poll::Ready(1));
+///
+/// let y: Or<Pinned, _> = Or::B(future::ready(2));
+/// assert_eq!(pin!(y).poll(&mut cx), Poll::Ready(2));
+/// ```
+impl<A, B> Future for Or<A, B>
+where A: Future, B: Future<Output = A::Output> {
+    type Output = A::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<A::Output> {
+        for_both!(self.as_pin_mut(), inner => inner.poll(cx))
+    }
+}
+
+impl<A, B> Or<A, B> {
+    /// Convert from `Or<A, B>` of futures to a future of
+    /// `Or<A::Output, B::Output>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// use std::future::{self, Future};
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// let x: Or<_, future::Ready<&str>> = Or::A(future::ready(1));
+    /// assert_eq!(pin!(x.factor_future()).poll(&mut cx), Poll::Ready(Or::A(1)));
+    ///
+    /// let y: Or<future::Ready<i32>, _> = Or::B(future::ready("two"));
+    /// assert_eq!(pin!(y.factor_future()).poll(&mut cx), Poll::Ready(Or::B("two")));
+    /// ```
+    pub fn factor_future(self) -> FactorFuture<A, B>
+    where A: Future, B: Future {
+        FactorFuture { inner: self }
+    }
+}
+
+/// A future resolving to the output of either side. See `Or::factor_future`.
+#[derive(Debug, Clone)]
+#[must_use = "futures do nothing unless polled"]
+pub struct FactorFuture<A, B> {
+    inner: Or<A, B>
+}
+
+impl<A, B> Future for FactorFuture<A, B>
+where A: Future, B: Future {
+    type Output = Or<A::Output, B::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        // Safety: `inner` is pinned structurally, like the sides of `Or`.
+        let inner = unsafe { self.map_unchecked_mut(|this| &mut this.inner) };
+
+        match inner.as_pin_mut() {
+            Or::A(a) => a.poll(cx).map(Or::A),
+            Or::B(b) => b.poll(cx).map(Or::B)
+        }
+    }
+}
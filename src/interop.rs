@@ -0,0 +1,226 @@
+//! Conversions between `Or` and similar types from other crates, each
+//! behind a feature of the same name as the crate.
+//!
+//! `A` always corresponds to the left side and `B` to the right.
+
+#[cfg(feature = "itertools")]
+use std::convert::TryFrom;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "futures")]
+use futures::future::Either as FutureEither;
+#[cfg(feature = "itertools")]
+use itertools::EitherOrBoth;
+
+use Or;
+#[cfg(feature = "itertools")]
+use These;
+
+/// ## Example
+///
+/// ```rust
+/// # extern crate either;
+/// # extern crate or;
+/// # use or::Or;
+/// use either::Either;
+///
+/// # fn main() {
+/// let x: Or<i32, &str> = Either::Left(2).into();
+/// assert_eq!(x, Or::A(2));
+///
+/// // Swapping an `Or` is the same as flipping an `Either`.
+/// assert_eq!(Either::from(x.clone().swap()), Either::from(x).flip());
+/// # }
+/// ```
+#[cfg(feature = "either")]
+impl<A, B> From<Either<A, B>> for Or<A, B> {
+    fn from(either: Either<A, B>) -> Self {
+        match either {
+            Either::Left(a) => Or::A(a),
+            Either::Right(b) => Or::B(b)
+        }
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # extern crate either;
+/// # extern crate or;
+/// # use or::Or;
+/// use either::Either;
+///
+/// # fn main() {
+/// let x: Either<i32, &str> = Or::B("two").into();
+/// assert_eq!(x, Either::Right("two"));
+/// assert_eq!(Or::from(x), Or::B("two"));
+/// # }
+/// ```
+#[cfg(feature = "either")]
+impl<A, B> From<Or<A, B>> for Either<A, B> {
+    fn from(or: Or<A, B>) -> Self {
+        match or {
+            Or::A(a) => Either::Left(a),
+            Or::B(b) => Either::Right(b)
+        }
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate or;
+/// # use or::Or;
+/// use futures::future::Either;
+///
+/// # fn main() {
+/// let x: Or<i32, &str> = Either::Right("two").into();
+/// assert_eq!(x, Or::B("two"));
+///
+/// // Swapping an `Or` is the same as flipping an `Either`.
+/// match (Either::from(x.clone().swap()), Either::from(x)) {
+///     (Either::Left("two"), Either::Right("two")) => {},
+///     _ => panic!("swap is inconsistent with Left and Right")
+/// }
+/// # }
+/// ```
+#[cfg(feature = "futures")]
+impl<A, B> From<FutureEither<A, B>> for Or<A, B> {
+    fn from(either: FutureEither<A, B>) -> Self {
+        match either {
+            FutureEither::Left(a) => Or::A(a),
+            FutureEither::Right(b) => Or::B(b)
+        }
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate or;
+/// # use or::Or;
+/// use futures::future::Either;
+///
+/// # fn main() {
+/// let x: Either<i32, &str> = Or::A(2).into();
+/// assert!(matches!(x, Either::Left(2)));
+/// assert_eq!(Or::from(x), Or::A(2));
+/// # }
+/// ```
+#[cfg(feature = "futures")]
+impl<A, B> From<Or<A, B>> for FutureEither<A, B> {
+    fn from(or: Or<A, B>) -> Self {
+        match or {
+            Or::A(a) => FutureEither::Left(a),
+            Or::B(b) => FutureEither::Right(b)
+        }
+    }
+}
+
+/// Fails with both values if given `Both`.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate itertools;
+/// # extern crate or;
+/// # use or::Or;
+/// use itertools::EitherOrBoth;
+/// use std::convert::TryFrom;
+///
+/// # fn main() {
+/// assert_eq!(Or::try_from(EitherOrBoth::Left::<i32, &str>(2)), Ok(Or::A(2)));
+/// assert_eq!(Or::try_from(EitherOrBoth::Right::<i32, &str>("two")), Ok(Or::B("two")));
+/// assert_eq!(Or::try_from(EitherOrBoth::Both(2, "two")), Err((2, "two")));
+///
+/// // Swapping an `Or` is the same as flipping an `EitherOrBoth`.
+/// let x: Or<i32, &str> = Or::A(2);
+/// assert_eq!(EitherOrBoth::from(x.clone().swap()), EitherOrBoth::from(x).flip());
+/// # }
+/// ```
+#[cfg(feature = "itertools")]
+impl<A, B> TryFrom<EitherOrBoth<A, B>> for Or<A, B> {
+    type Error = (A, B);
+
+    fn try_from(either: EitherOrBoth<A, B>) -> Result<Self, (A, B)> {
+        match either {
+            EitherOrBoth::Left(a) => Ok(Or::A(a)),
+            EitherOrBoth::Right(b) => Ok(Or::B(b)),
+            EitherOrBoth::Both(a, b) => Err((a, b))
+        }
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # extern crate itertools;
+/// # extern crate or;
+/// # use or::Or;
+/// use itertools::EitherOrBoth;
+///
+/// # fn main() {
+/// assert_eq!(EitherOrBoth::from(Or::A::<i32, &str>(2)), EitherOrBoth::Left(2));
+/// assert_eq!(EitherOrBoth::from(Or::B::<i32, &str>("two")), EitherOrBoth::Right("two"));
+/// # }
+/// ```
+#[cfg(feature = "itertools")]
+impl<A, B> From<Or<A, B>> for EitherOrBoth<A, B> {
+    fn from(or: Or<A, B>) -> Self {
+        match or {
+            Or::A(a) => EitherOrBoth::Left(a),
+            Or::B(b) => EitherOrBoth::Right(b)
+        }
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # extern crate itertools;
+/// # extern crate or;
+/// # use or::These;
+/// use itertools::EitherOrBoth;
+///
+/// # fn main() {
+/// assert_eq!(These::from(EitherOrBoth::Both(2, "two")), These::Both(2, "two"));
+/// assert_eq!(These::from(EitherOrBoth::Left::<i32, &str>(2)), These::This(2));
+/// # }
+/// ```
+#[cfg(feature = "itertools")]
+impl<A, B> From<EitherOrBoth<A, B>> for These<A, B> {
+    fn from(either: EitherOrBoth<A, B>) -> Self {
+        match either {
+            EitherOrBoth::Left(a) => These::This(a),
+            EitherOrBoth::Right(b) => These::That(b),
+            EitherOrBoth::Both(a, b) => These::Both(a, b)
+        }
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # extern crate itertools;
+/// # extern crate or;
+/// # use or::These;
+/// use itertools::EitherOrBoth;
+///
+/// # fn main() {
+/// assert_eq!(EitherOrBoth::from(These::Both(2, "two")), EitherOrBoth::Both(2, "two"));
+/// assert_eq!(EitherOrBoth::from(These::That::<i32, &str>("two")), EitherOrBoth::Right("two"));
+/// # }
+/// ```
+#[cfg(feature = "itertools")]
+impl<A, B> From<These<A, B>> for EitherOrBoth<A, B> {
+    fn from(these: These<A, B>) -> Self {
+        match these {
+            These::This(a) => EitherOrBoth::Left(a),
+            These::That(b) => EitherOrBoth::Right(b),
+            These::Both(a, b) => EitherOrBoth::Both(a, b)
+        }
+    }
+}
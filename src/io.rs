@@ -0,0 +1,169 @@
+//! `std::io` support for `Or`.
+
+use std::fmt;
+use std::io::{self, BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+
+use Or;
+
+/// `Or` is a reader if both sides are readers.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::fs::File;
+/// use std::io::{Cursor, Read};
+///
+/// fn open(path: Option<&str>) -> Or<File, Cursor<Vec<u8>>> {
+///     match path {
+///         Some(path) => Or::A(File::open(path).unwrap()),
+///         None => Or::B(Cursor::new(b"from memory".to_vec()))
+///     }
+/// }
+///
+/// let mut contents = String::new();
+/// open(None).read_to_string(&mut contents).unwrap();
+/// assert_eq!(contents, "from memory");
+/// ```
+impl<A, B> Read for Or<A, B>
+where A: Read, B: Read {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.read(buf))
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut]) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.read_vectored(bufs))
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.read_to_end(buf))
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.read_to_string(buf))
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        for_both!(*self, ref mut inner => inner.read_exact(buf))
+    }
+}
+
+/// `Or` is a buffered reader if both sides are buffered readers.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::io::{BufRead, BufReader};
+///
+/// let mut x: Or<BufReader<&[u8]>, &[u8]> = Or::A(BufReader::new(&b"one\ntwo"[..]));
+/// assert_eq!(x.fill_buf().unwrap(), b"one\ntwo");
+/// x.consume(4);
+/// assert_eq!(x.lines().next().unwrap().unwrap(), "two");
+///
+/// let mut z: Or<&[u8], &[u8]> = Or::B(&b"skip;keep"[..]);
+/// assert_eq!(z.skip_until(b';').unwrap(), 5);
+/// assert_eq!(z.fill_buf().unwrap(), b"keep");
+///
+/// let y: Or<BufReader<&[u8]>, &[u8]> = Or::B(&b"three\nfour"[..]);
+/// assert_eq!(y.lines().count(), 2);
+/// ```
+impl<A, B> BufRead for Or<A, B>
+where A: BufRead, B: BufRead {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        for_both!(*self, ref mut inner => inner.fill_buf())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        for_both!(*self, ref mut inner => inner.consume(amt))
+    }
+
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.read_until(byte, buf))
+    }
+
+    fn skip_until(&mut self, byte: u8) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.skip_until(byte))
+    }
+
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.read_line(buf))
+    }
+}
+
+/// `Or` is a writer if both sides are writers.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::io::{self, IoSlice, Write};
+///
+/// let mut x: Or<Vec<u8>, io::Sink> = Or::A(Vec::new());
+/// x.write_all(b"hello").unwrap();
+/// x.write_vectored(&[IoSlice::new(b", "), IoSlice::new(b"world")]).unwrap();
+/// write!(x, "{}", '!').unwrap();
+/// x.flush().unwrap();
+///
+/// assert_eq!(x.a().unwrap(), b"hello, world!");
+/// ```
+impl<A, B> Write for Or<A, B>
+where A: Write, B: Write {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.write(buf))
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+        for_both!(*self, ref mut inner => inner.write_vectored(bufs))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for_both!(*self, ref mut inner => inner.flush())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        for_both!(*self, ref mut inner => inner.write_all(buf))
+    }
+
+    fn write_fmt(&mut self, fmt: fmt::Arguments) -> io::Result<()> {
+        for_both!(*self, ref mut inner => inner.write_fmt(fmt))
+    }
+}
+
+/// `Or` is seekable if both sides are seekable.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::io::{Cursor, Read, Seek, SeekFrom};
+///
+/// let mut x: Or<Cursor<Vec<u8>>, Cursor<&[u8]>> = Or::B(Cursor::new(&b"abcdef"[..]));
+/// assert_eq!(x.seek(SeekFrom::End(-2)).unwrap(), 4);
+/// assert_eq!(x.stream_position().unwrap(), 4);
+///
+/// x.rewind().unwrap();
+/// x.seek_relative(1).unwrap();
+///
+/// let mut buf = [0; 2];
+/// x.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"bc");
+/// ```
+impl<A, B> Seek for Or<A, B>
+where A: Seek, B: Seek {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        for_both!(*self, ref mut inner => inner.seek(pos))
+    }
+
+    fn rewind(&mut self) -> io::Result<()> {
+        for_both!(*self, ref mut inner => inner.rewind())
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        for_both!(*self, ref mut inner => inner.stream_position())
+    }
+
+    fn seek_relative(&mut self, offset: i64) -> io::Result<()> {
+        for_both!(*self, ref mut inner => inner.seek_relative(offset))
+    }
+}
@@ -0,0 +1,608 @@
+//! Iterator support for `Or`.
+//!
+//! `Or` of two iterators is itself an iterator, and `OrIterExt` adds
+//! adapters for iterators which yield `Or` items. The merge functions
+//! combine two sorted iterators into one of `These` or `Or` items.
+
+use std::cmp::Ordering;
+use std::iter::{FilterMap, FromIterator, FusedIterator, Peekable};
+
+use {Or, These};
+
+/// `Or` is an iterator if both sides are iterators over the same `Item`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// fn evens(all: bool) -> Or<std::vec::IntoIter<i32>, std::iter::Empty<i32>> {
+///     if all { Or::A(vec![2, 4, 6].into_iter()) } else { Or::B(std::iter::empty()) }
+/// }
+///
+/// let mut sum = 0;
+/// for x in evens(true) { sum += x; }
+///
+/// assert_eq!(sum, 12);
+/// assert_eq!(evens(true).len(), 3);
+/// assert_eq!(evens(true).rev().next(), Some(6));
+/// assert_eq!(evens(false).count(), 0);
+/// assert_eq!(evens(true).fold(0, |acc, x| acc + x), 12);
+/// ```
+impl<A, B> Iterator for Or<A, B>
+where A: Iterator, B: Iterator<Item = A::Item> {
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        for_both!(*self, ref mut inner => inner.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        for_both!(*self, ref inner => inner.size_hint())
+    }
+
+    fn fold<Acc, F>(self, init: Acc, f: F) -> Acc
+    where F: FnMut(Acc, A::Item) -> Acc {
+        for_both!(self, inner => inner.fold(init, f))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<A::Item> {
+        for_both!(*self, ref mut inner => inner.nth(n))
+    }
+
+    fn count(self) -> usize {
+        for_both!(self, inner => inner.count())
+    }
+
+    fn last(self) -> Option<A::Item> {
+        for_both!(self, inner => inner.last())
+    }
+}
+
+impl<A, B> DoubleEndedIterator for Or<A, B>
+where A: DoubleEndedIterator, B: DoubleEndedIterator<Item = A::Item> {
+    fn next_back(&mut self) -> Option<A::Item> {
+        for_both!(*self, ref mut inner => inner.next_back())
+    }
+
+    fn rfold<Acc, F>(self, init: Acc, f: F) -> Acc
+    where F: FnMut(Acc, A::Item) -> Acc {
+        for_both!(self, inner => inner.rfold(init, f))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<A::Item> {
+        for_both!(*self, ref mut inner => inner.nth_back(n))
+    }
+}
+
+impl<A, B> ExactSizeIterator for Or<A, B>
+where A: ExactSizeIterator, B: ExactSizeIterator<Item = A::Item> {
+    fn len(&self) -> usize {
+        for_both!(*self, ref inner => inner.len())
+    }
+}
+
+impl<A, B> FusedIterator for Or<A, B>
+where A: FusedIterator, B: FusedIterator<Item = A::Item> {}
+
+impl<A, B> Or<A, B> {
+    /// Convert from `Or<A, B>` to `Or<A::IntoIter, B::IntoIter>`
+    ///
+    /// `Or` can't implement `IntoIterator` for arbitrary `IntoIterator`
+    /// sides, as it already gets `IntoIterator` from being an `Iterator`.
+    /// This method covers the rest, such as an `Or` of two collections.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// # use std::collections::BTreeSet;
+    /// let x: Or<Vec<i32>, BTreeSet<i32>> = Or::A(vec![3, 1, 2]);
+    /// assert_eq!(x.into_iter().collect::<Vec<_>>(), vec![3, 1, 2]);
+    ///
+    /// let y: Or<Vec<i32>, BTreeSet<i32>> = Or::B(vec![3, 1, 2].into_iter().collect());
+    /// assert_eq!(y.into_iter().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> Or<A::IntoIter, B::IntoIter>
+    where A: IntoIterator, B: IntoIterator<Item = A::Item> {
+        self.bimap(IntoIterator::into_iter, IntoIterator::into_iter)
+    }
+}
+
+/// Collects `A`s and `B`s into a pair of collections.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// # use std::collections::HashSet;
+/// let items = vec![Or::A("one".to_string()), Or::B(2), Or::B(2)];
+/// let (names, ids): (Vec<String>, HashSet<u32>) = items.into_iter().collect();
+///
+/// assert_eq!(names, vec!["one"]);
+/// assert_eq!(ids.len(), 1);
+/// ```
+impl<A, B, CA, CB> FromIterator<Or<A, B>> for (CA, CB)
+where CA: Default + Extend<A>, CB: Default + Extend<B> {
+    fn from_iter<I>(iter: I) -> (CA, CB)
+    where I: IntoIterator<Item = Or<A, B>> {
+        let mut collections = (CA::default(), CB::default());
+        collections.extend(iter);
+        collections
+    }
+}
+
+/// Extends a pair of collections with `A`s and `B`s respectively.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// let mut pair: (Vec<i32>, String) = (vec![1], "a".to_string());
+///
+/// pair.extend(vec![Or::A(2), Or::B('b')]);
+/// pair.extend(vec![Or::B('c'), Or::A(3)]);
+///
+/// assert_eq!(pair, (vec![1, 2, 3], "abc".to_string()));
+/// ```
+impl<A, B, CA, CB> Extend<Or<A, B>> for (CA, CB)
+where CA: Extend<A>, CB: Extend<B> {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = Or<A, B>> {
+        for item in iter {
+            match item {
+                Or::A(a) => self.0.extend(Some(a)),
+                Or::B(b) => self.1.extend(Some(b))
+            }
+        }
+    }
+}
+
+/// Collects every `A` into `V`, stopping at the first `B`.
+///
+/// If a `B` is found, no further items are taken from the iterator and the
+/// `B` is returned, in the same way as collecting into a `Result`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// let items = vec![Or::A(1), Or::A(2)];
+/// let all: Or<Vec<i32>, &str> = items.into_iter().collect();
+/// assert_eq!(all, Or::A(vec![1, 2]));
+///
+/// let mut items = vec![Or::A(1), Or::B("stop"), Or::A(3)].into_iter();
+/// let stopped: Or<Vec<i32>, &str> = items.by_ref().collect();
+/// assert_eq!(stopped, Or::B("stop"));
+/// assert_eq!(items.next(), Some(Or::A(3)));
+/// ```
+impl<A, B, V> FromIterator<Or<A, B>> for Or<V, B>
+where V: FromIterator<A> {
+    fn from_iter<I>(iter: I) -> Or<V, B>
+    where I: IntoIterator<Item = Or<A, B>> {
+        let mut found = None;
+
+        let collected = iter.into_iter().scan(&mut found, |found, item| {
+            match item {
+                Or::A(a) => Some(a),
+                Or::B(b) => { **found = Some(b); None }
+            }
+        }).collect();
+
+        match found {
+            Some(b) => Or::B(b),
+            None => Or::A(collected)
+        }
+    }
+}
+
+/// Extension methods for iterators over `Or<A, B>`
+///
+/// Every method is defined in terms of the `Or::a` and `Or::b`
+/// projections, and so behaves the same as applying them to each item.
+pub trait OrIterExt<A, B>: Iterator<Item = Or<A, B>> {
+    /// Splits the iterator into a collection of `A`s and a collection of `B`s
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, OrIterExt};
+    /// # use std::collections::HashSet;
+    /// let items = vec![Or::A("one"), Or::B(2), Or::A("three"), Or::B(2)];
+    /// let (names, ids): (Vec<&str>, HashSet<i32>) = items.into_iter().partition_or();
+    ///
+    /// assert_eq!(names, vec!["one", "three"]);
+    /// assert_eq!(ids.len(), 1);
+    /// ```
+    fn partition_or<CA, CB>(self) -> (CA, CB)
+    where Self: Sized, CA: Default + Extend<A>, CB: Default + Extend<B> {
+        self.collect()
+    }
+
+    /// Yields the value of every `A`, skipping any `B`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, OrIterExt};
+    /// let items = vec![Or::A(1), Or::B("two"), Or::A(3)];
+    /// assert_eq!(items.into_iter().as_only().collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_only(self) -> AsOnly<Self, A, B>
+    where Self: Sized {
+        self.filter_map(Or::a)
+    }
+
+    /// Yields the value of every `B`, skipping any `A`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, OrIterExt};
+    /// let items = vec![Or::A(1), Or::B("two"), Or::A(3)];
+    /// assert_eq!(items.into_iter().bs_only().collect::<Vec<_>>(), vec!["two"]);
+    /// ```
+    fn bs_only(self) -> BsOnly<Self, A, B>
+    where Self: Sized {
+        self.filter_map(Or::b)
+    }
+
+    /// Applies `f` to every `A`, passing every `B` through untouched
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, OrIterExt};
+    /// let items = vec![Or::A(1), Or::B("two")];
+    /// let mapped: Vec<_> = items.into_iter().map_a(|a| a * 10).collect();
+    ///
+    /// assert_eq!(mapped, vec![Or::A(10), Or::B("two")]);
+    /// ```
+    fn map_a<C, F>(self, f: F) -> MapA<Self, F>
+    where Self: Sized, F: FnMut(A) -> C {
+        MapA { iter: self, f }
+    }
+
+    /// Applies `f` to every `B`, passing every `A` through untouched
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, OrIterExt};
+    /// let items = vec![Or::A(1), Or::B("two")];
+    /// let mapped: Vec<_> = items.into_iter().map_b(str::len).collect();
+    ///
+    /// assert_eq!(mapped, vec![Or::A(1), Or::B(3)]);
+    /// ```
+    fn map_b<D, F>(self, f: F) -> MapB<Self, F>
+    where Self: Sized, F: FnMut(B) -> D {
+        MapB { iter: self, f }
+    }
+
+    /// Returns the first `A`, consuming items up to and including it
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, OrIterExt};
+    /// let mut items = vec![Or::B("zero"), Or::A(1), Or::B("two")].into_iter();
+    ///
+    /// assert_eq!(items.first_a(), Some(1));
+    /// assert_eq!(items.next(), Some(Or::B("two")));
+    /// assert_eq!(items.first_a(), None);
+    /// ```
+    fn first_a(&mut self) -> Option<A>
+    where Self: Sized {
+        self.find_map(Or::a)
+    }
+
+    /// Returns the first `B`, consuming items up to and including it
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, OrIterExt};
+    /// let mut items = vec![Or::A(0), Or::B("one"), Or::A(2)].into_iter();
+    ///
+    /// assert_eq!(items.first_b(), Some("one"));
+    /// assert_eq!(items.next(), Some(Or::A(2)));
+    /// assert_eq!(items.first_b(), None);
+    /// ```
+    fn first_b(&mut self) -> Option<B>
+    where Self: Sized {
+        self.find_map(Or::b)
+    }
+}
+
+impl<I, A, B> OrIterExt<A, B> for I where I: Iterator<Item = Or<A, B>> {}
+
+/// An iterator over the `A` of every item. See `OrIterExt::as_only`.
+pub type AsOnly<I, A, B> = FilterMap<I, fn(Or<A, B>) -> Option<A>>;
+
+/// An iterator over the `B` of every item. See `OrIterExt::bs_only`.
+pub type BsOnly<I, A, B> = FilterMap<I, fn(Or<A, B>) -> Option<B>>;
+
+/// An iterator which maps the `A` of every item. See `OrIterExt::map_a`.
+#[derive(Debug, Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapA<I, F> {
+    iter: I,
+    f: F
+}
+
+impl<I, F, A, B, C> Iterator for MapA<I, F>
+where I: Iterator<Item = Or<A, B>>, F: FnMut(A) -> C {
+    type Item = Or<C, B>;
+
+    fn next(&mut self) -> Option<Or<C, B>> {
+        let f = &mut self.f;
+        self.iter.next().map(|item| item.map_a(f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, A, B, C> DoubleEndedIterator for MapA<I, F>
+where I: DoubleEndedIterator<Item = Or<A, B>>, F: FnMut(A) -> C {
+    fn next_back(&mut self) -> Option<Or<C, B>> {
+        let f = &mut self.f;
+        self.iter.next_back().map(|item| item.map_a(f))
+    }
+}
+
+impl<I, F, A, B, C> ExactSizeIterator for MapA<I, F>
+where I: ExactSizeIterator<Item = Or<A, B>>, F: FnMut(A) -> C {}
+
+impl<I, F, A, B, C> FusedIterator for MapA<I, F>
+where I: FusedIterator<Item = Or<A, B>>, F: FnMut(A) -> C {}
+
+/// An iterator which maps the `B` of every item. See `OrIterExt::map_b`.
+#[derive(Debug, Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MapB<I, F> {
+    iter: I,
+    f: F
+}
+
+impl<I, F, A, B, D> Iterator for MapB<I, F>
+where I: Iterator<Item = Or<A, B>>, F: FnMut(B) -> D {
+    type Item = Or<A, D>;
+
+    fn next(&mut self) -> Option<Or<A, D>> {
+        let f = &mut self.f;
+        self.iter.next().map(|item| item.map_b(f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, A, B, D> DoubleEndedIterator for MapB<I, F>
+where I: DoubleEndedIterator<Item = Or<A, B>>, F: FnMut(B) -> D {
+    fn next_back(&mut self) -> Option<Or<A, D>> {
+        let f = &mut self.f;
+        self.iter.next_back().map(|item| item.map_b(f))
+    }
+}
+
+impl<I, F, A, B, D> ExactSizeIterator for MapB<I, F>
+where I: ExactSizeIterator<Item = Or<A, B>>, F: FnMut(B) -> D {}
+
+impl<I, F, A, B, D> FusedIterator for MapB<I, F>
+where I: FusedIterator<Item = Or<A, B>>, F: FnMut(B) -> D {}
+
+/// Merges two sorted iterators, pairing up items which compare equal
+///
+/// Items only in `left` are yielded as `This`, items only in `right` as
+/// `That`, and equal items as `Both`. Both iterators must be sorted
+/// according to `cmp`. When a key is duplicated, the runs of equal items
+/// are paired up one to one in order, and any excess is yielded alone.
+///
+/// The merge is lazy, and never buffers more than one item from each side.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::These;
+/// use or::iter::merge_join_by;
+///
+/// let snapshot = vec![(1, "a"), (2, "b"), (2, "c"), (4, "d")];
+/// let live = vec![1, 2, 3];
+///
+/// let diff: Vec<_> = merge_join_by(snapshot, live, |&(s, _), l| s.cmp(l)).collect();
+///
+/// assert_eq!(diff, vec![These::Both((1, "a"), 1),
+///                       These::Both((2, "b"), 2),
+///                       These::This((2, "c")),
+///                       These::That(3),
+///                       These::This((4, "d"))]);
+///
+/// // Between every item being paired up and none of them being paired.
+/// let hint = merge_join_by(vec![1, 2], vec![2, 3, 4], |l, r| l.cmp(r)).size_hint();
+/// assert_eq!(hint, (3, Some(5)));
+/// ```
+pub fn merge_join_by<L, R, F>(left: L, right: R, cmp: F) -> MergeJoinBy<L::IntoIter, R::IntoIter, F>
+where L: IntoIterator, R: IntoIterator, F: FnMut(&L::Item, &R::Item) -> Ordering {
+    MergeJoinBy {
+        left: left.into_iter().peekable(),
+        right: right.into_iter().peekable(),
+        cmp
+    }
+}
+
+/// Merges two iterators sorted by key, pairing up items with equal keys
+///
+/// This is `merge_join_by`, comparing the keys of each item.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::These;
+/// use or::iter::merge_join_by_key;
+///
+/// let names = vec![(1, "one"), (3, "three")];
+/// let squares = vec![(1, 1), (2, 4)];
+///
+/// let joined: Vec<_> = merge_join_by_key(names, squares, |n| n.0, |s| s.0).collect();
+///
+/// assert_eq!(joined, vec![These::Both((1, "one"), (1, 1)),
+///                         These::That((2, 4)),
+///                         These::This((3, "three"))]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn merge_join_by_key<L, R, K, FL, FR>(left: L, right: R, mut left_key: FL, mut right_key: FR)
+    -> MergeJoinBy<L::IntoIter, R::IntoIter, impl FnMut(&L::Item, &R::Item) -> Ordering>
+where L: IntoIterator, R: IntoIterator, K: Ord,
+      FL: FnMut(&L::Item) -> K, FR: FnMut(&R::Item) -> K {
+    merge_join_by(left, right, move |l, r| left_key(l).cmp(&right_key(r)))
+}
+
+/// Merges two sorted iterators into one sorted iterator of `Or` items
+///
+/// Items from `left` are yielded as `A` and items from `right` as `B`.
+/// Both iterators must be sorted according to `cmp`. Items which compare
+/// equal are all yielded, with those from `left` first.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use or::iter::merge_by;
+///
+/// let merged: Vec<_> = merge_by(vec![1, 3, 3], vec![2, 3], |l, r| l.cmp(r)).collect();
+///
+/// assert_eq!(merged, vec![Or::A(1), Or::B(2), Or::A(3), Or::A(3), Or::B(3)]);
+///
+/// let hint = merge_by(vec![1, 2], vec![2, 3, 4], |l, r| l.cmp(r)).size_hint();
+/// assert_eq!(hint, (5, Some(5)));
+/// ```
+pub fn merge_by<L, R, F>(left: L, right: R, cmp: F) -> MergeBy<L::IntoIter, R::IntoIter, F>
+where L: IntoIterator, R: IntoIterator, F: FnMut(&L::Item, &R::Item) -> Ordering {
+    MergeBy {
+        left: left.into_iter().peekable(),
+        right: right.into_iter().peekable(),
+        cmp
+    }
+}
+
+/// Merges two iterators sorted by key into one sorted iterator of `Or`
+/// items
+///
+/// This is `merge_by`, comparing the keys of each item.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use or::iter::merge_by_key;
+///
+/// let words = vec!["a", "ccc"];
+/// let numbers = vec![2, 3];
+///
+/// let merged: Vec<_> = merge_by_key(words, numbers, |w| w.len(), |&n| n).collect();
+///
+/// assert_eq!(merged, vec![Or::A("a"), Or::B(2), Or::A("ccc"), Or::B(3)]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn merge_by_key<L, R, K, FL, FR>(left: L, right: R, mut left_key: FL, mut right_key: FR)
+    -> MergeBy<L::IntoIter, R::IntoIter, impl FnMut(&L::Item, &R::Item) -> Ordering>
+where L: IntoIterator, R: IntoIterator, K: Ord,
+      FL: FnMut(&L::Item) -> K, FR: FnMut(&R::Item) -> K {
+    merge_by(left, right, move |l, r| left_key(l).cmp(&right_key(r)))
+}
+
+/// An iterator over two merged iterators. See `merge_join_by`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MergeJoinBy<L, R, F>
+where L: Iterator, R: Iterator {
+    left: Peekable<L>,
+    right: Peekable<R>,
+    cmp: F
+}
+
+impl<L, R, F> Iterator for MergeJoinBy<L, R, F>
+where L: Iterator, R: Iterator, F: FnMut(&L::Item, &R::Item) -> Ordering {
+    type Item = These<L::Item, R::Item>;
+
+    fn next(&mut self) -> Option<These<L::Item, R::Item>> {
+        let ordering = match (self.left.peek(), self.right.peek()) {
+            (Some(l), Some(r)) => (self.cmp)(l, r),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None
+        };
+
+        match ordering {
+            Ordering::Less => self.left.next().map(These::This),
+            Ordering::Greater => self.right.next().map(These::That),
+            Ordering::Equal => match (self.left.next(), self.right.next()) {
+                (Some(l), Some(r)) => Some(These::Both(l, r)),
+                _ => unreachable!("both sides were peeked")
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_low, left_high) = self.left.size_hint();
+        let (right_low, right_high) = self.right.size_hint();
+
+        // Every item is yielded exactly once, but pairs share an item.
+        let high = match (left_high, right_high) {
+            (Some(l), Some(r)) => l.checked_add(r),
+            _ => None
+        };
+
+        (left_low.max(right_low), high)
+    }
+}
+
+impl<L, R, F> FusedIterator for MergeJoinBy<L, R, F>
+where L: FusedIterator, R: FusedIterator, F: FnMut(&L::Item, &R::Item) -> Ordering {}
+
+/// An iterator over two merged iterators. See `merge_by`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MergeBy<L, R, F>
+where L: Iterator, R: Iterator {
+    left: Peekable<L>,
+    right: Peekable<R>,
+    cmp: F
+}
+
+impl<L, R, F> Iterator for MergeBy<L, R, F>
+where L: Iterator, R: Iterator, F: FnMut(&L::Item, &R::Item) -> Ordering {
+    type Item = Or<L::Item, R::Item>;
+
+    fn next(&mut self) -> Option<Or<L::Item, R::Item>> {
+        let take_left = match (self.left.peek(), self.right.peek()) {
+            (Some(l), Some(r)) => (self.cmp)(l, r) != Ordering::Greater,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return None
+        };
+
+        if take_left {
+            self.left.next().map(Or::A)
+        } else {
+            self.right.next().map(Or::B)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_low, left_high) = self.left.size_hint();
+        let (right_low, right_high) = self.right.size_hint();
+
+        let high = match (left_high, right_high) {
+            (Some(l), Some(r)) => l.checked_add(r),
+            _ => None
+        };
+
+        (left_low.saturating_add(right_low), high)
+    }
+}
+
+impl<L, R, F> FusedIterator for MergeBy<L, R, F>
+where L: FusedIterator, R: FusedIterator, F: FnMut(&L::Item, &R::Item) -> Ordering {}
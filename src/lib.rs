@@ -6,6 +6,59 @@
 //! A generalized Result.
 //!
 
+// Evaluates `$result` against whichever side of an `Or` is present, binding
+// it to `$pattern`. Used to delegate trait methods to the active side.
+macro_rules! for_both {
+    ($value:expr, $pattern:pat => $result:expr) => {
+        match $value {
+            $crate::Or::A($pattern) => $result,
+            $crate::Or::B($pattern) => $result
+        }
+    }
+}
+
+#[cfg(feature = "either")]
+extern crate either;
+#[cfg(feature = "futures")]
+extern crate futures;
+#[cfg(feature = "itertools")]
+extern crate itertools;
+#[cfg(feature = "serde")]
+extern crate serde as serde_crate;
+#[cfg(feature = "tokio")]
+extern crate tokio;
+
+#[cfg(test)]
+extern crate quickcheck;
+
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+
+pub use iter::OrIterExt;
+pub use nary::{Or3, Or4, Or5, Or6, Or7, Or8, Or9, Or10, Or11, Or12};
+pub use parse::ParseError;
+pub use these::These;
+
+#[cfg(feature = "tokio")]
+mod async_io;
+mod convert;
+pub mod coproduct;
+pub mod diff;
+mod error;
+pub mod flatten;
+pub mod future;
+#[cfg(any(feature = "either", feature = "futures", feature = "itertools"))]
+mod interop;
+pub mod iter;
+mod io;
+mod nary;
+mod parse;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod these;
+#[cfg(feature = "futures")]
+mod stream;
+
 /// A generalized Result, just a two-variant enum.
 ///
 /// Much of the functionality of Result and Option is not redundantly
@@ -13,6 +66,8 @@
 /// the `a` and `b` methods, which you should combine with `as_ref` and
 /// `as_mut` to get the full spectrum of provided functionality.
 #[derive(Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde_crate::Serialize, serde_crate::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "::serde_crate"))]
 pub enum Or<A, B> {
     /// One variant
     A(A),
@@ -138,6 +193,93 @@ impl<A, B> Or<A, B> {
         }
     }
 
+    /// Convert from `Pin<&Or<A, B>>` to `Or<Pin<&A>, Pin<&B>>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// use std::pin::Pin;
+    ///
+    /// let x: Or<i32, ()> = Or::A(2);
+    /// let pinned = Pin::new(&x);
+    /// assert_eq!(pinned.as_pin_ref().a().map(|a| *a), Some(2));
+    /// ```
+    pub fn as_pin_ref(self: Pin<&Self>) -> Or<Pin<&A>, Pin<&B>> {
+        // Safety: see `as_pin_mut`.
+        unsafe {
+            match *Pin::get_ref(self) {
+                Or::A(ref a) => Or::A(Pin::new_unchecked(a)),
+                Or::B(ref b) => Or::B(Pin::new_unchecked(b)),
+            }
+        }
+    }
+
+    /// Convert from `Pin<&mut Or<A, B>>` to `Or<Pin<&mut A>, Pin<&mut B>>`
+    ///
+    /// This is what lets a pinned `Or` of futures, streams or async I/O
+    /// types drive whichever side is present.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// use std::pin::Pin;
+    ///
+    /// let mut x: Or<i32, ()> = Or::A(2);
+    /// if let Or::A(a) = Pin::new(&mut x).as_pin_mut() {
+    ///     *a.get_mut() += 1;
+    /// }
+    /// assert_eq!(x, Or::A(3));
+    /// ```
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Or<Pin<&mut A>, Pin<&mut B>> {
+        // Safety: the sides are never moved out of a pinned `Or`, and `Or`
+        // implements neither `Drop` nor `Unpin` itself, so pinning is
+        // structural.
+        unsafe {
+            match *Pin::get_unchecked_mut(self) {
+                Or::A(ref mut a) => Or::A(Pin::new_unchecked(a)),
+                Or::B(ref mut b) => Or::B(Pin::new_unchecked(b)),
+            }
+        }
+    }
+
+    /// Convert from `&Or<A, B>` to `Or<&A::Target, &B::Target>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<String, Vec<u8>> = Or::A("hello".to_string());
+    /// let y: Or<&str, &[u8]> = x.as_deref();
+    /// assert_eq!(y, Or::A("hello"));
+    /// ```
+    pub fn as_deref(&self) -> Or<&A::Target, &B::Target>
+    where A: Deref, B: Deref {
+        match *self {
+            Or::A(ref a) => Or::A(a),
+            Or::B(ref b) => Or::B(b),
+        }
+    }
+
+    /// Convert from `&mut Or<A, B>` to `Or<&mut A::Target, &mut B::Target>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let mut x: Or<String, Vec<u8>> = Or::B(vec![1, 2]);
+    /// x.as_deref_mut().b().map(|b| b[0] = 3);
+    /// assert_eq!(x, Or::B(vec![3, 2]));
+    /// ```
+    pub fn as_deref_mut(&mut self) -> Or<&mut A::Target, &mut B::Target>
+    where A: DerefMut, B: DerefMut {
+        match *self {
+            Or::A(ref mut a) => Or::A(a),
+            Or::B(ref mut b) => Or::B(b),
+        }
+    }
+
     /// Convert from `Or<A, B>` to `Or<B, A>`
     ///
     /// Consumes `self` and returns a new `Or`
@@ -156,5 +298,777 @@ impl<A, B> Or<A, B> {
             Or::B(a) => Or::A(a)
         }
     }
+
+    /// Pairs a value with whichever side is present, as the first element
+    ///
+    /// This is the inverse of `factor_first`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, bool> = Or::B(true);
+    /// assert_eq!(x.distribute_first('c'), Or::B(('c', true)));
+    /// ```
+    pub fn distribute_first<C>(self, c: C) -> Or<(C, A), (C, B)> {
+        match self {
+            Or::A(a) => Or::A((c, a)),
+            Or::B(b) => Or::B((c, b))
+        }
+    }
+
+    /// Pairs a value with whichever side is present, as the second element
+    ///
+    /// This is the inverse of `factor_second`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, bool> = Or::A(2);
+    /// assert_eq!(x.distribute_second('c'), Or::A((2, 'c')));
+    /// ```
+    pub fn distribute_second<C>(self, c: C) -> Or<(A, C), (B, C)> {
+        match self {
+            Or::A(a) => Or::A((a, c)),
+            Or::B(b) => Or::B((b, c))
+        }
+    }
+
+    /// Maps an `Or<A, B>` to `Or<C, B>` by applying a function to `A`
+    ///
+    /// `B` is passed through untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// assert_eq!(x.map_a(|a| a * 10), Or::A(20));
+    ///
+    /// let y: Or<i32, &str> = Or::B("untouched");
+    /// assert_eq!(y.map_a(|a| a * 10), Or::B("untouched"));
+    /// ```
+    pub fn map_a<C, F>(self, f: F) -> Or<C, B>
+    where F: FnOnce(A) -> C {
+        match self {
+            Or::A(a) => Or::A(f(a)),
+            Or::B(b) => Or::B(b)
+        }
+    }
+
+    /// Maps an `Or<A, B>` to `Or<A, D>` by applying a function to `B`
+    ///
+    /// `A` is passed through untouched.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<&str, i32> = Or::B(2);
+    /// assert_eq!(x.map_b(|b| b * 10), Or::B(20));
+    ///
+    /// let y: Or<&str, i32> = Or::A("untouched");
+    /// assert_eq!(y.map_b(|b| b * 10), Or::A("untouched"));
+    /// ```
+    pub fn map_b<D, F>(self, f: F) -> Or<A, D>
+    where F: FnOnce(B) -> D {
+        match self {
+            Or::A(a) => Or::A(a),
+            Or::B(b) => Or::B(f(b))
+        }
+    }
+
+    /// Maps an `Or<A, B>` to `Or<C, D>` by applying `f` to `A` or `g` to `B`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// assert_eq!(x.bimap(|a| a + 1, |b| b.len()), Or::A(3));
+    ///
+    /// let y: Or<i32, &str> = Or::B("four");
+    /// assert_eq!(y.bimap(|a| a + 1, |b| b.len()), Or::B(4));
+    /// ```
+    pub fn bimap<C, D, F, G>(self, f: F, g: G) -> Or<C, D>
+    where F: FnOnce(A) -> C, G: FnOnce(B) -> D {
+        match self {
+            Or::A(a) => Or::A(f(a)),
+            Or::B(b) => Or::B(g(b))
+        }
+    }
+
+    /// Collapses an `Or<A, B>` to a single value by applying `f` to `A`
+    /// or `g` to `B`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// assert_eq!(x.either(|a| a as usize, |b| b.len()), 2);
+    ///
+    /// let y: Or<i32, &str> = Or::B("four");
+    /// assert_eq!(y.either(|a| a as usize, |b| b.len()), 4);
+    /// ```
+    pub fn either<C, F, G>(self, f: F, g: G) -> C
+    where F: FnOnce(A) -> C, G: FnOnce(B) -> C {
+        match self {
+            Or::A(a) => f(a),
+            Or::B(b) => g(b)
+        }
+    }
+
+    /// Like `either`, but threads a shared value into whichever function
+    /// is called
+    ///
+    /// This is useful when both functions need the same mutable state,
+    /// which two closures could not otherwise borrow at once.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let mut log = Vec::new();
+    ///
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// let y: Or<i32, &str> = Or::B("four");
+    ///
+    /// for or in vec![x, y] {
+    ///     or.either_with(&mut log,
+    ///                    |log, a| log.push(format!("a: {}", a)),
+    ///                    |log, b| log.push(format!("b: {}", b)));
+    /// }
+    ///
+    /// assert_eq!(log, vec!["a: 2", "b: four"]);
+    /// ```
+    pub fn either_with<C, R, F, G>(self, init: C, f: F, g: G) -> R
+    where F: FnOnce(C, A) -> R, G: FnOnce(C, B) -> R {
+        match self {
+            Or::A(a) => f(init, a),
+            Or::B(b) => g(init, b)
+        }
+    }
+
+    /// Calls `f` with the value of `A`, which may itself produce either
+    /// variant, leaving `B` untouched
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// fn halve(x: i32) -> Or<i32, String> {
+    ///     if x % 2 == 0 { Or::A(x / 2) } else { Or::B(format!("{} is odd", x)) }
+    /// }
+    ///
+    /// let x: Or<i32, String> = Or::A(8);
+    /// assert_eq!(x.and_then_a(halve).and_then_a(halve), Or::A(2));
+    ///
+    /// let y: Or<i32, String> = Or::A(6);
+    /// assert_eq!(y.and_then_a(halve).and_then_a(halve),
+    ///            Or::B("3 is odd".to_string()));
+    /// ```
+    pub fn and_then_a<C, F>(self, f: F) -> Or<C, B>
+    where F: FnOnce(A) -> Or<C, B> {
+        match self {
+            Or::A(a) => f(a),
+            Or::B(b) => Or::B(b)
+        }
+    }
+
+    /// Calls `f` with the value of `B`, which may itself produce either
+    /// variant, leaving `A` untouched
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// fn parse(s: &str) -> Or<i32, String> {
+    ///     s.parse().map(Or::A).unwrap_or_else(|_| Or::B(s.to_uppercase()))
+    /// }
+    ///
+    /// let x: Or<i32, &str> = Or::B("12");
+    /// assert_eq!(x.and_then_b(parse), Or::A(12));
+    ///
+    /// let y: Or<i32, &str> = Or::B("twelve");
+    /// assert_eq!(y.and_then_b(parse), Or::B("TWELVE".to_string()));
+    /// ```
+    pub fn and_then_b<D, F>(self, f: F) -> Or<A, D>
+    where F: FnOnce(B) -> Or<A, D> {
+        match self {
+            Or::A(a) => Or::A(a),
+            Or::B(b) => f(b)
+        }
+    }
+
+    /// Returns the value of `A`, or `default` if the `Or` is `B`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// assert_eq!(x.a_or(7), 2);
+    ///
+    /// let y: Or<i32, &str> = Or::B("nope");
+    /// assert_eq!(y.a_or(7), 7);
+    /// ```
+    pub fn a_or(self, default: A) -> A {
+        match self {
+            Or::A(a) => a,
+            Or::B(_) => default
+        }
+    }
+
+    /// Returns the value of `B`, or `default` if the `Or` is `A`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<&str, i32> = Or::A("nope");
+    /// assert_eq!(x.b_or(7), 7);
+    ///
+    /// let y: Or<&str, i32> = Or::B(2);
+    /// assert_eq!(y.b_or(7), 2);
+    /// ```
+    pub fn b_or(self, default: B) -> B {
+        match self {
+            Or::A(_) => default,
+            Or::B(b) => b
+        }
+    }
+
+    /// Returns the value of `A`, or computes one from `B`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<usize, &str> = Or::A(2);
+    /// assert_eq!(x.a_or_else(|b| b.len()), 2);
+    ///
+    /// let y: Or<usize, &str> = Or::B("four");
+    /// assert_eq!(y.a_or_else(|b| b.len()), 4);
+    /// ```
+    pub fn a_or_else<F>(self, f: F) -> A
+    where F: FnOnce(B) -> A {
+        match self {
+            Or::A(a) => a,
+            Or::B(b) => f(b)
+        }
+    }
+
+    /// Returns the value of `B`, or computes one from `A`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<&str, usize> = Or::A("four");
+    /// assert_eq!(x.b_or_else(|a| a.len()), 4);
+    ///
+    /// let y: Or<&str, usize> = Or::B(2);
+    /// assert_eq!(y.b_or_else(|a| a.len()), 2);
+    /// ```
+    pub fn b_or_else<F>(self, f: F) -> B
+    where F: FnOnce(A) -> B {
+        match self {
+            Or::A(a) => f(a),
+            Or::B(b) => b
+        }
+    }
+
+    /// Calls `f` with a reference to `A`, if present, and returns `self`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let mut seen = None;
+    ///
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// let x = x.inspect_a(|a| seen = Some(*a));
+    ///
+    /// assert_eq!(x, Or::A(2));
+    /// assert_eq!(seen, Some(2));
+    /// ```
+    pub fn inspect_a<F>(self, f: F) -> Self
+    where F: FnOnce(&A) {
+        if let Or::A(ref a) = self { f(a) }
+        self
+    }
+
+    /// Calls `f` with a reference to `B`, if present, and returns `self`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let mut seen = None;
+    ///
+    /// let x: Or<i32, &str> = Or::A(2);
+    /// let x = x.inspect_b(|b| seen = Some(*b));
+    ///
+    /// assert_eq!(x, Or::A(2));
+    /// assert_eq!(seen, None);
+    /// ```
+    pub fn inspect_b<F>(self, f: F) -> Self
+    where F: FnOnce(&B) {
+        if let Or::B(ref b) = self { f(b) }
+        self
+    }
+
+    /// Converts whichever side is present into a common type `U`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<u8, u16> = Or::A(7);
+    /// assert_eq!(x.converge::<u32>(), 7);
+    ///
+    /// let y: Or<&str, char> = Or::B('c');
+    /// assert_eq!(y.converge::<String>(), "c");
+    /// ```
+    pub fn converge<U>(self) -> U
+    where A: Into<U>, B: Into<U> {
+        match self {
+            Or::A(a) => a.into(),
+            Or::B(b) => b.into()
+        }
+    }
+}
+
+impl<T> Or<T, T> {
+    /// Returns the value of whichever side is present
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, i32> = Or::B(4);
+    /// assert_eq!(x.into_inner(), 4);
+    ///
+    /// let y: Or<String, String> = Or::A("hello".to_string());
+    /// let inner: &String = y.as_ref().into_inner();
+    /// assert_eq!(inner, "hello");
+    /// ```
+    pub fn into_inner(self) -> T {
+        match self {
+            Or::A(a) => a,
+            Or::B(b) => b
+        }
+    }
+
+    /// Returns a reference to the value of whichever side is present
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<Vec<i32>, Vec<i32>> = Or::A(vec![1, 2]);
+    /// assert_eq!(x.as_inner().len(), 2);
+    /// ```
+    pub fn as_inner(&self) -> &T {
+        match *self {
+            Or::A(ref a) => a,
+            Or::B(ref b) => b
+        }
+    }
+
+    /// Returns a mutable reference to the value of whichever side is
+    /// present
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let mut x: Or<Vec<i32>, Vec<i32>> = Or::B(vec![1]);
+    /// x.as_inner_mut().push(2);
+    /// assert_eq!(x, Or::B(vec![1, 2]));
+    /// ```
+    pub fn as_inner_mut(&mut self) -> &mut T {
+        match *self {
+            Or::A(ref mut a) => a,
+            Or::B(ref mut b) => b
+        }
+    }
+
+    /// Maps an `Or<T, T>` to `Or<U, U>` by applying `f` to whichever side
+    /// is present, keeping the side
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, i32> = Or::B(4);
+    /// assert_eq!(x.map_both(|n| n * 2), Or::B(8));
+    /// ```
+    pub fn map_both<U, F>(self, f: F) -> Or<U, U>
+    where F: FnOnce(T) -> U {
+        match self {
+            Or::A(a) => Or::A(f(a)),
+            Or::B(b) => Or::B(f(b))
+        }
+    }
+}
+
+impl<'a, A, B> Or<&'a A, &'a B> {
+    /// Maps an `Or<&A, &B>` to an `Or<A, B>` by cloning whichever side is
+    /// present
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<String, i32> = Or::A("hello".to_string());
+    /// assert_eq!(x.as_ref().cloned(), x);
+    /// ```
+    pub fn cloned(self) -> Or<A, B>
+    where A: Clone, B: Clone {
+        match self {
+            Or::A(a) => Or::A(a.clone()),
+            Or::B(b) => Or::B(b.clone())
+        }
+    }
+
+    /// Maps an `Or<&A, &B>` to an `Or<A, B>` by copying whichever side is
+    /// present
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, char> = Or::B('c');
+    /// assert_eq!(x.as_ref().copied(), Or::B('c'));
+    /// ```
+    pub fn copied(self) -> Or<A, B>
+    where A: Copy, B: Copy {
+        match self {
+            Or::A(&a) => Or::A(a),
+            Or::B(&b) => Or::B(b)
+        }
+    }
 }
 
+impl<'a, A, B> Or<&'a mut A, &'a mut B> {
+    /// Maps an `Or<&mut A, &mut B>` to an `Or<A, B>` by cloning whichever
+    /// side is present
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let mut x: Or<String, i32> = Or::A("hello".to_string());
+    /// assert_eq!(x.as_mut().cloned(), Or::A("hello".to_string()));
+    /// ```
+    pub fn cloned(self) -> Or<A, B>
+    where A: Clone, B: Clone {
+        match self {
+            Or::A(a) => Or::A(a.clone()),
+            Or::B(b) => Or::B(b.clone())
+        }
+    }
+
+    /// Maps an `Or<&mut A, &mut B>` to an `Or<A, B>` by copying whichever
+    /// side is present
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let mut x: Or<i32, char> = Or::A(2);
+    /// assert_eq!(x.as_mut().copied(), Or::A(2));
+    /// ```
+    pub fn copied(self) -> Or<A, B>
+    where A: Copy, B: Copy {
+        match self {
+            Or::A(&mut a) => Or::A(a),
+            Or::B(&mut b) => Or::B(b)
+        }
+    }
+}
+
+impl<A, B> Or<Option<A>, Option<B>> {
+    /// Transposes an `Or` of `Option`s into an `Option` of an `Or`
+    ///
+    /// `None` on either side becomes `None`. As `None` does not say which
+    /// side it came from, there is no inverse to this method.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<Option<i32>, Option<&str>> = Or::B(Some("two"));
+    /// assert_eq!(x.transpose(), Some(Or::B("two")));
+    ///
+    /// let y: Or<Option<i32>, Option<&str>> = Or::A(None);
+    /// assert_eq!(y.transpose(), None);
+    /// ```
+    pub fn transpose(self) -> Option<Or<A, B>> {
+        match self {
+            Or::A(a) => a.map(Or::A),
+            Or::B(b) => b.map(Or::B)
+        }
+    }
+}
+
+impl<A, B, E> Or<Result<A, E>, Result<B, E>> {
+    /// Transposes an `Or` of `Result`s with the same error type into a
+    /// `Result` of an `Or`
+    ///
+    /// An error on either side becomes the error. To keep track of which
+    /// side it came from, use `transpose_err`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<Result<i32, String>, Result<bool, String>> = Or::A(Ok(2));
+    /// assert_eq!(x.transpose(), Ok(Or::A(2)));
+    ///
+    /// let y: Or<Result<i32, String>, Result<bool, String>> = Or::B(Err("no".to_string()));
+    /// assert_eq!(y.transpose(), Err("no".to_string()));
+    /// ```
+    pub fn transpose(self) -> Result<Or<A, B>, E> {
+        match self {
+            Or::A(a) => a.map(Or::A),
+            Or::B(b) => b.map(Or::B)
+        }
+    }
+}
+
+impl<A, B, E, F> Or<Result<A, E>, Result<B, F>> {
+    /// Transposes an `Or` of `Result`s into a `Result` of an `Or`, with
+    /// an `Or` of the errors
+    ///
+    /// Unlike `transpose`, this is lossless, and `untranspose_err` is its
+    /// inverse.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<Result<i32, String>, Result<bool, ()>> = Or::A(Err("no".to_string()));
+    /// assert_eq!(x.transpose_err(), Err(Or::A("no".to_string())));
+    ///
+    /// let y: Or<Result<i32, String>, Result<bool, ()>> = Or::B(Ok(true));
+    /// assert_eq!(y.transpose_err(), Ok(Or::B(true)));
+    /// ```
+    pub fn transpose_err(self) -> Result<Or<A, B>, Or<E, F>> {
+        match self {
+            Or::A(a) => a.map(Or::A).map_err(Or::A),
+            Or::B(b) => b.map(Or::B).map_err(Or::B)
+        }
+    }
+
+    /// Transposes a `Result` of an `Or` with an `Or` of errors into an
+    /// `Or` of `Result`s
+    ///
+    /// This is the inverse of `transpose_err`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Result<Or<i32, bool>, Or<String, ()>> = Err(Or::B(()));
+    /// assert_eq!(Or::untranspose_err(x), Or::<Result<i32, String>, _>::B(Err(())));
+    /// ```
+    pub fn untranspose_err(result: Result<Or<A, B>, Or<E, F>>) -> Self {
+        match result {
+            Ok(Or::A(a)) => Or::A(Ok(a)),
+            Ok(Or::B(b)) => Or::B(Ok(b)),
+            Err(Or::A(e)) => Or::A(Err(e)),
+            Err(Or::B(f)) => Or::B(Err(f))
+        }
+    }
+}
+
+impl<A, B, C> Or<Or<A, B>, C> {
+    /// Convert from `Or<Or<A, B>, C>` to `Or<A, Or<B, C>>`
+    ///
+    /// This is the inverse of `assoc_left`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<Or<i32, bool>, char> = Or::A(Or::B(true));
+    /// assert_eq!(x.assoc_right(), Or::B(Or::A(true)));
+    /// ```
+    pub fn assoc_right(self) -> Or<A, Or<B, C>> {
+        match self {
+            Or::A(Or::A(a)) => Or::A(a),
+            Or::A(Or::B(b)) => Or::B(Or::A(b)),
+            Or::B(c) => Or::B(Or::B(c))
+        }
+    }
+}
+
+impl<A, B, C> Or<A, Or<B, C>> {
+    /// Convert from `Or<A, Or<B, C>>` to `Or<Or<A, B>, C>`
+    ///
+    /// This is the inverse of `assoc_right`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<i32, Or<bool, char>> = Or::B(Or::A(true));
+    /// assert_eq!(x.assoc_left(), Or::A(Or::B(true)));
+    /// ```
+    pub fn assoc_left(self) -> Or<Or<A, B>, C> {
+        match self {
+            Or::A(a) => Or::A(Or::A(a)),
+            Or::B(Or::A(b)) => Or::A(Or::B(b)),
+            Or::B(Or::B(c)) => Or::B(c)
+        }
+    }
+}
+
+impl<A, B, C> Or<(C, A), (C, B)> {
+    /// Factors out a value shared by the first element of both sides
+    ///
+    /// This is the inverse of `distribute_first`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<(u8, i32), (u8, bool)> = Or::B((7, true));
+    /// assert_eq!(x.factor_first(), (7, Or::B(true)));
+    /// ```
+    pub fn factor_first(self) -> (C, Or<A, B>) {
+        match self {
+            Or::A((c, a)) => (c, Or::A(a)),
+            Or::B((c, b)) => (c, Or::B(b))
+        }
+    }
+}
+
+impl<A, B, C> Or<(A, C), (B, C)> {
+    /// Factors out a value shared by the second element of both sides
+    ///
+    /// This is the inverse of `distribute_second`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<(i32, u8), (bool, u8)> = Or::A((2, 7));
+    /// assert_eq!(x.factor_second(), (Or::A(2), 7));
+    /// ```
+    pub fn factor_second(self) -> (Or<A, B>, C) {
+        match self {
+            Or::A((a, c)) => (Or::A(a), c),
+            Or::B((b, c)) => (Or::B(b), c)
+        }
+    }
+}
+
+impl<A1, A2, B1, B2> Or<(A1, A2), (B1, B2)> {
+    /// Convert from an `Or` of pairs to a pair of `Or`s
+    ///
+    /// Both `Or`s in the result are on the same side as `self`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::Or;
+    /// let x: Or<(i32, char), (bool, &str)> = Or::B((true, "two"));
+    /// assert_eq!(x.unzip(), (Or::B(true), Or::B("two")));
+    /// ```
+    pub fn unzip(self) -> (Or<A1, B1>, Or<A2, B2>) {
+        match self {
+            Or::A((a1, a2)) => (Or::A(a1), Or::A(a2)),
+            Or::B((b1, b2)) => (Or::B(b1), Or::B(b2))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use Or;
+
+    #[test]
+    fn transpose_inverts_lifting_into_either_side() {
+        fn prop(x: Option<Result<u8, i8>>) -> bool {
+            let x = x.map(Or::from_result);
+            let in_a = x.clone().map_or(Or::A(None), |or| or.bimap(Some, Some));
+            let in_b = x.clone().map_or(Or::B(None), |or| or.bimap(Some, Some));
+            in_a.transpose() == x && in_b.transpose() == x
+        }
+
+        quickcheck(prop as fn(Option<Result<u8, i8>>) -> bool);
+    }
+
+    #[test]
+    fn transpose_err_round_trips() {
+        type Transposed = Result<Or<u8, i8>, Or<u16, i16>>;
+        type Untransposed = Or<Result<u8, u16>, Result<i8, i16>>;
+
+        fn prop(x: Result<Result<u8, i8>, Result<u16, i16>>) -> bool {
+            let transposed: Transposed = x.map(Or::from_result).map_err(Or::from_result);
+            let untransposed: Untransposed = Or::untranspose_err(transposed.clone());
+            untransposed.clone().transpose_err() == transposed
+                && Or::untranspose_err(untransposed.clone().transpose_err()) == untransposed
+        }
+
+        quickcheck(prop as fn(Result<Result<u8, i8>, Result<u16, i16>>) -> bool);
+    }
+
+    #[test]
+    fn assoc_round_trips() {
+        fn right_then_left(x: Result<Result<u8, i8>, u16>) -> bool {
+            let x = Or::from_result(x.map(Or::from_result));
+            x.clone().assoc_right().assoc_left() == x
+        }
+
+        fn left_then_right(x: Result<u8, Result<i8, u16>>) -> bool {
+            let x = Or::from_result(x.map_err(Or::from_result));
+            x.clone().assoc_left().assoc_right() == x
+        }
+
+        quickcheck(right_then_left as fn(Result<Result<u8, i8>, u16>) -> bool);
+        quickcheck(left_then_right as fn(Result<u8, Result<i8, u16>>) -> bool);
+    }
+
+    #[test]
+    fn factor_inverts_distribute() {
+        fn first(c: u16, x: Result<u8, i8>) -> bool {
+            let x = Or::from_result(x);
+            let distributed = x.clone().distribute_first(c);
+            distributed.clone().factor_first() == (c, x)
+                && distributed.clone().factor_first().1.distribute_first(c) == distributed
+        }
+
+        fn second(c: u16, x: Result<u8, i8>) -> bool {
+            let x = Or::from_result(x);
+            let distributed = x.clone().distribute_second(c);
+            distributed.clone().factor_second() == (x, c)
+                && distributed.clone().factor_second().0.distribute_second(c) == distributed
+        }
+
+        quickcheck(first as fn(u16, Result<u8, i8>) -> bool);
+        quickcheck(second as fn(u16, Result<u8, i8>) -> bool);
+    }
+
+    #[test]
+    fn unzip_keeps_the_side() {
+        fn prop(x: Result<(u8, u16), (i8, i16)>) -> bool {
+            let x = Or::from_result(x);
+            let rezipped = match x.clone().unzip() {
+                (Or::A(a1), Or::A(a2)) => Or::A((a1, a2)),
+                (Or::B(b1), Or::B(b2)) => Or::B((b1, b2)),
+                _ => return false
+            };
+            rezipped == x
+        }
+
+        quickcheck(prop as fn(Result<(u8, u16), (i8, i16)>) -> bool);
+    }
+}
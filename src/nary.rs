@@ -0,0 +1,260 @@
+//! Sum types with more than two variants.
+
+use Or;
+use coproduct::{Embed, Inject, Nested};
+use flatten::Leaf;
+
+// Expands to the right-nested `Or` chain over the given types, so
+// `nested!(A, B, C)` is `Or<A, Or<B, C>>`.
+macro_rules! nested {
+    ($a:ident, $b:ident) => { Or<$a, $b> };
+    ($a:ident, $($rest:ident),+) => { Or<$a, nested!($($rest),+)> };
+}
+
+// Defines an N-ary `Or` with the same API as `Or`.
+//
+// `$prev` is the (N-1)-ary type, and each variant after the first is
+// paired with the variant of `$prev` it shifts down to when the first
+// variant is split off, which is how the conversions to and from the
+// nested `Or` chain are built up.
+macro_rules! or_n {
+    ($(#[$attr:meta])*
+     $name:ident, $prev:ident,
+     $first:ident $first_lc:ident $first_is:ident,
+     $($var:ident $lc:ident $is:ident => $pvar:ident),+) => {
+        $(#[$attr])*
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
+        pub enum $name<$first, $($var),+> {
+            #[doc = concat!("The `", stringify!($first), "` variant")]
+            $first($first),
+            $(
+                #[doc = concat!("The `", stringify!($var), "` variant")]
+                $var($var)
+            ),+
+        }
+
+        impl<$first, $($var),+> $name<$first, $($var),+> {
+            #[doc = concat!("Returns true if the `", stringify!($name), "` is `", stringify!($first), "`")]
+            pub fn $first_is(&self) -> bool {
+                self.as_ref().$first_lc().is_some()
+            }
+
+            $(
+                #[doc = concat!("Returns true if the `", stringify!($name), "` is `", stringify!($var), "`")]
+                pub fn $is(&self) -> bool {
+                    self.as_ref().$lc().is_some()
+                }
+            )+
+
+            #[doc = concat!("Converts from `", stringify!($name), "` to `Option<", stringify!($first), ">`")]
+            ///
+            /// This method consumes `self` and discards any other variant.
+            pub fn $first_lc(self) -> Option<$first> {
+                match self {
+                    $name::$first(x) => Some(x),
+                    _ => None
+                }
+            }
+
+            $(
+                #[doc = concat!("Converts from `", stringify!($name), "` to `Option<", stringify!($var), ">`")]
+                ///
+                /// This method consumes `self` and discards any other variant.
+                pub fn $lc(self) -> Option<$var> {
+                    match self {
+                        $name::$var(x) => Some(x),
+                        _ => None
+                    }
+                }
+            )+
+
+            /// Convert from a reference to an `Or` of references
+            ///
+            /// The returned value contains references into the existing
+            /// value, which is left in place.
+            pub fn as_ref(&self) -> $name<&$first, $(&$var),+> {
+                match *self {
+                    $name::$first(ref x) => $name::$first(x),
+                    $($name::$var(ref x) => $name::$var(x)),+
+                }
+            }
+
+            /// Convert from a mutable reference to an `Or` of mutable
+            /// references
+            ///
+            /// The returned value contains references into the existing
+            /// value, which is left in place.
+            pub fn as_mut(&mut self) -> $name<&mut $first, $(&mut $var),+> {
+                match *self {
+                    $name::$first(ref mut x) => $name::$first(x),
+                    $($name::$var(ref mut x) => $name::$var(x)),+
+                }
+            }
+        }
+
+        impl<$first, $($var),+> Leaf for $name<$first, $($var),+> {}
+
+        impl<$first, $($var,)+ T, Index> Inject<T, Index> for $name<$first, $($var),+>
+        where nested!($first, $($var),+): Inject<T, Index> {
+            fn inject(value: T) -> Self {
+                <nested!($first, $($var),+)>::inject(value).into()
+            }
+        }
+
+        impl<$first, $($var,)+ Target, Index> Embed<Target, Nested<Index>> for $name<$first, $($var),+>
+        where nested!($first, $($var),+): Embed<Target, Index> {
+            fn embed(self) -> Target {
+                <nested!($first, $($var),+)>::from(self).embed()
+            }
+        }
+
+        impl<$first, $($var),+> From<$name<$first, $($var),+>> for nested!($first, $($var),+) {
+            fn from(or: $name<$first, $($var),+>) -> Self {
+                match or {
+                    $name::$first(x) => Or::A(x),
+                    $($name::$var(x) => Or::B($prev::$pvar(x).into())),+
+                }
+            }
+        }
+
+        impl<$first, $($var),+> From<nested!($first, $($var),+)> for $name<$first, $($var),+> {
+            fn from(or: nested!($first, $($var),+)) -> Self {
+                match or {
+                    Or::A(x) => $name::$first(x),
+                    Or::B(rest) => {
+                        let rest: $prev<$($var),+> = rest.into();
+                        match rest {
+                            $($prev::$pvar(x) => $name::$var(x)),+
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+or_n! {
+    /// A three-variant `Or`
+    ///
+    /// `Or3` through `Or12` all have the same API as `Or`, and convert
+    /// losslessly to and from the right-nested `Or` chain of the same
+    /// types, so `Or3<A, B, C>` corresponds to `Or<A, Or<B, C>>`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, Or3};
+    /// let mut x: Or3<i32, &str, ()> = Or3::B("hello");
+    ///
+    /// assert!(x.is_b());
+    /// assert!(!x.is_a() && !x.is_c());
+    /// assert_eq!(x.as_ref().b(), Some(&"hello"));
+    ///
+    /// x.as_mut().b().map(|b| *b = "world");
+    /// assert_eq!(x.clone().b(), Some("world"));
+    ///
+    /// let nested: Or<i32, Or<&str, ()>> = x.clone().into();
+    /// assert_eq!(nested, Or::B(Or::A("world")));
+    /// assert_eq!(Or3::from(nested), x);
+    /// ```
+    Or3, Or,
+    A a is_a,
+    B b is_b => A, C c is_c => B
+}
+
+or_n! {
+    /// A four-variant `Or`. See `Or3` for details.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, Or4};
+    /// let x: Or4<(), (), i32, ()> = Or4::C(3);
+    ///
+    /// let nested: Or<(), Or<(), Or<i32, ()>>> = x.clone().into();
+    /// assert_eq!(nested, Or::B(Or::B(Or::A(3))));
+    /// assert_eq!(Or4::from(nested), x);
+    /// ```
+    Or4, Or3,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C
+}
+
+or_n! {
+    /// A five-variant `Or`. See `Or3` for details.
+    Or5, Or4,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C, E e is_e => D
+}
+
+or_n! {
+    /// A six-variant `Or`. See `Or3` for details.
+    Or6, Or5,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C, E e is_e => D,
+    F f is_f => E
+}
+
+or_n! {
+    /// A seven-variant `Or`. See `Or3` for details.
+    Or7, Or6,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C, E e is_e => D,
+    F f is_f => E, G g is_g => F
+}
+
+or_n! {
+    /// An eight-variant `Or`. See `Or3` for details.
+    Or8, Or7,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C, E e is_e => D,
+    F f is_f => E, G g is_g => F, H h is_h => G
+}
+
+or_n! {
+    /// A nine-variant `Or`. See `Or3` for details.
+    Or9, Or8,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C, E e is_e => D,
+    F f is_f => E, G g is_g => F, H h is_h => G, I i is_i => H
+}
+
+or_n! {
+    /// A ten-variant `Or`. See `Or3` for details.
+    Or10, Or9,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C, E e is_e => D,
+    F f is_f => E, G g is_g => F, H h is_h => G, I i is_i => H,
+    J j is_j => I
+}
+
+or_n! {
+    /// An eleven-variant `Or`. See `Or3` for details.
+    Or11, Or10,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C, E e is_e => D,
+    F f is_f => E, G g is_g => F, H h is_h => G, I i is_i => H,
+    J j is_j => I, K k is_k => J
+}
+
+or_n! {
+    /// A twelve-variant `Or`. See `Or3` for details.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, Or12};
+    /// type Big = Or12<u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, &'static str>;
+    ///
+    /// let x: Big = Or12::L("last");
+    /// assert!(x.is_l());
+    ///
+    /// let nested = Or::from(x.clone());
+    /// assert_eq!(Big::from(nested), x);
+    /// ```
+    Or12, Or11,
+    A a is_a,
+    B b is_b => A, C c is_c => B, D d is_d => C, E e is_e => D,
+    F f is_f => E, G g is_g => F, H h is_h => G, I i is_i => H,
+    J j is_j => I, K k is_k => J, L l is_l => K
+}
@@ -0,0 +1,99 @@
+//! `FromStr` support for `Or`.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use Or;
+
+/// Parses `A` from a string if possible, and `B` otherwise.
+///
+/// `A` always takes precedence: a string which both sides can parse is
+/// parsed as `A`. Because of this, `Display` and `parse` round-trip
+/// whenever the displayed value could not also be parsed as `A`.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// # use std::path::PathBuf;
+/// let port: Or<u16, PathBuf> = "8080".parse().unwrap();
+/// assert_eq!(port, Or::A(8080));
+///
+/// let socket: Or<u16, PathBuf> = "/run/app.sock".parse().unwrap();
+/// assert_eq!(socket, Or::B(PathBuf::from("/run/app.sock")));
+///
+/// // Round-trips, since "true" is not an `i32`.
+/// let x: Or<i32, bool> = Or::B(true);
+/// assert_eq!(x.to_string().parse::<Or<i32, bool>>().unwrap(), x);
+///
+/// // Does not round-trip, as "80" is also a valid `u16`.
+/// let y: Or<u16, String> = Or::B("80".to_string());
+/// assert_eq!(y.to_string().parse::<Or<u16, String>>().unwrap(), Or::A(80));
+/// ```
+impl<A, B> FromStr for Or<A, B>
+where A: FromStr, B: FromStr {
+    type Err = ParseError<A::Err, B::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(a) => Ok(Or::A(a)),
+            Err(a) => match s.parse() {
+                Ok(b) => Ok(Or::B(b)),
+                Err(b) => Err(ParseError { a, b })
+            }
+        }
+    }
+}
+
+/// The error returned when neither side of an `Or` could be parsed
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::Or;
+/// use std::num::ParseIntError;
+/// use std::str::ParseBoolError;
+///
+/// let err = "maybe".parse::<Or<i32, bool>>().unwrap_err();
+///
+/// assert_eq!(err.to_string(),
+///            "could not parse either side, as A: invalid digit found in string, \
+///             as B: provided string was not `true` or `false`");
+///
+/// let (a, b): (ParseIntError, ParseBoolError) = err.into_inner();
+/// assert_eq!(a, "maybe".parse::<i32>().unwrap_err());
+/// assert_eq!(b, "maybe".parse::<bool>().unwrap_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ParseError<EA, EB> {
+    a: EA,
+    b: EB
+}
+
+impl<EA, EB> ParseError<EA, EB> {
+    /// Returns the error from parsing `A`
+    pub fn a(&self) -> &EA {
+        &self.a
+    }
+
+    /// Returns the error from parsing `B`
+    pub fn b(&self) -> &EB {
+        &self.b
+    }
+
+    /// Returns the errors from parsing `A` and `B`
+    pub fn into_inner(self) -> (EA, EB) {
+        (self.a, self.b)
+    }
+}
+
+impl<EA, EB> fmt::Display for ParseError<EA, EB>
+where EA: fmt::Display, EB: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not parse either side, as A: {}, as B: {}", self.a, self.b)
+    }
+}
+
+impl<EA, EB> Error for ParseError<EA, EB>
+where EA: Error, EB: Error {}
@@ -0,0 +1,358 @@
+//! Serde support for `Or`, behind the `serde` feature.
+//!
+//! `Or` is externally tagged by default, like any other enum:
+//!
+//! ```rust
+//! # extern crate or;
+//! # extern crate serde_json;
+//! # use or::Or;
+//! # fn main() {
+//! let x: Or<i32, String> = Or::A(5);
+//! let json = serde_json::to_string(&x).unwrap();
+//!
+//! assert_eq!(json, r#"{"A":5}"#);
+//! assert_eq!(serde_json::from_str::<Or<i32, String>>(&json).unwrap(), x);
+//! # }
+//! ```
+//!
+//! The modules here provide the other representations supported by serde,
+//! for use with `#[serde(with = "...")]`.
+
+use serde_crate::de::{Deserialize, Deserializer, Error};
+
+use Or;
+
+use self::content::{Content, ContentRefDeserializer};
+
+mod content;
+
+/// The order in which the sides are tried by `deserialize_in_order`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Try `A`, then `B`
+    AThenB,
+    /// Try `B`, then `A`
+    BThenA
+}
+
+/// Deserializes an untagged `Or`, trying each side in the given order
+///
+/// The input is buffered so that it can be read again by the second side
+/// if the first fails. If both fail, the error reports why each did, in
+/// the order they were tried, rather than only that the data matched
+/// neither.
+///
+/// Strings and bytes borrowed from the input stay borrowed in the buffer,
+/// so sides like `&str` can be deserialized with `#[serde(borrow)]`.
+///
+/// This is usually used through the `untagged`, `a_first` or `b_first`
+/// modules.
+pub fn deserialize_in_order<'de, A, B, D>(deserializer: D, order: Order)
+                                          -> Result<Or<A, B>, D::Error>
+where A: Deserialize<'de>, B: Deserialize<'de>, D: Deserializer<'de> {
+    let content = Content::deserialize(deserializer)?;
+
+    let as_a = || A::deserialize(ContentRefDeserializer::<D::Error>::new(&content));
+    let as_b = || B::deserialize(ContentRefDeserializer::<D::Error>::new(&content));
+
+    match order {
+        Order::AThenB => match as_a() {
+            Ok(a) => Ok(Or::A(a)),
+            Err(a_err) => as_b().map(Or::B).map_err(|b_err| {
+                D::Error::custom(format_args!(
+                    "data did not match either side, as A: {}, as B: {}", a_err, b_err))
+            })
+        },
+        Order::BThenA => match as_b() {
+            Ok(b) => Ok(Or::B(b)),
+            Err(b_err) => as_a().map(Or::A).map_err(|a_err| {
+                D::Error::custom(format_args!(
+                    "data did not match either side, as B: {}, as A: {}", b_err, a_err))
+            })
+        }
+    }
+}
+
+/// The untagged representation, where only the content is written
+///
+/// When deserializing, `A` is tried first and then `B`, and if neither
+/// matches the error reports why each did not.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate or;
+/// # extern crate serde;
+/// # #[macro_use] extern crate serde_derive;
+/// # extern crate serde_json;
+/// # use or::Or;
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Port {
+///     #[serde(with = "or::serde::untagged")]
+///     port: Or<u16, String>
+/// }
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Borrowed<'a> {
+///     #[serde(borrow, with = "or::serde::untagged")]
+///     name: Or<&'a str, u32>
+/// }
+///
+/// # fn main() {
+/// for (port, json) in vec![(Or::A(80), r#"{"port":80}"#),
+///                          (Or::B("http".to_string()), r#"{"port":"http"}"#)] {
+///     let port = Port { port };
+///     assert_eq!(serde_json::to_string(&port).unwrap(), json);
+///     assert_eq!(serde_json::from_str::<Port>(json).unwrap(), port);
+/// }
+///
+/// let borrowed = serde_json::from_str::<Borrowed>(r#"{"name":"hi"}"#).unwrap();
+/// assert_eq!(borrowed.name, Or::A("hi"));
+/// # }
+/// ```
+pub mod untagged {
+    use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use Or;
+
+    /// Serializes an `Or` as only the content of whichever side is present
+    pub fn serialize<A, B, S>(or: &Or<A, B>, serializer: S) -> Result<S::Ok, S::Error>
+    where A: Serialize, B: Serialize, S: Serializer {
+        for_both!(*or, ref inner => inner.serialize(serializer))
+    }
+
+    /// Deserializes an `Or`, trying `A` first and then `B`
+    pub fn deserialize<'de, A, B, D>(deserializer: D) -> Result<Or<A, B>, D::Error>
+    where A: Deserialize<'de>, B: Deserialize<'de>, D: Deserializer<'de> {
+        super::deserialize_in_order(deserializer, super::Order::AThenB)
+    }
+}
+
+/// The untagged representation, trying `A` first and reporting both
+/// errors if neither side matches
+///
+/// This is the same as `untagged`, named to pair with `b_first`.
+///
+/// This is particularly useful for the common configuration pattern of a
+/// field that may be either a shorthand string or a full table.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate or;
+/// # extern crate serde;
+/// # #[macro_use] extern crate serde_derive;
+/// # extern crate serde_json;
+/// # extern crate serde_yaml;
+/// # extern crate toml;
+/// # use or::Or;
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Detailed {
+///     version: String,
+///     #[serde(default)]
+///     features: Vec<String>
+/// }
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Dependency {
+///     #[serde(with = "or::serde::a_first")]
+///     dep: Or<String, Detailed>
+/// }
+///
+/// # fn main() {
+/// let short = Dependency { dep: Or::A("1.0".to_string()) };
+/// let long = Dependency {
+///     dep: Or::B(Detailed { version: "1.0".to_string(), features: vec!["io".to_string()] })
+/// };
+///
+/// assert_eq!(serde_json::from_str::<Dependency>(r#"{"dep": "1.0"}"#).unwrap(), short);
+/// assert_eq!(toml::from_str::<Dependency>(r#"dep = "1.0""#).unwrap(), short);
+/// assert_eq!(serde_yaml::from_str::<Dependency>("dep: '1.0'").unwrap(), short);
+///
+/// let json = r#"{"dep": {"version": "1.0", "features": ["io"]}}"#;
+/// assert_eq!(serde_json::from_str::<Dependency>(json).unwrap(), long);
+///
+/// let toml = r#"dep = { version = "1.0", features = ["io"] }"#;
+/// assert_eq!(toml::from_str::<Dependency>(toml).unwrap(), long);
+///
+/// let yaml = "dep:\n  version: '1.0'\n  features: [io]";
+/// assert_eq!(serde_yaml::from_str::<Dependency>(yaml).unwrap(), long);
+///
+/// let err = serde_json::from_str::<Dependency>(r#"{"dep": 5}"#).unwrap_err().to_string();
+/// assert!(err.starts_with("data did not match either side, \
+///                          as A: invalid type: integer `5`, expected a string, \
+///                          as B: invalid type: integer `5`, expected struct Detailed"));
+/// # }
+/// ```
+pub mod a_first {
+    pub use super::untagged::{deserialize, serialize};
+}
+
+/// The untagged representation, trying `B` first and reporting both
+/// errors if neither side matches
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate or;
+/// # extern crate serde;
+/// # #[macro_use] extern crate serde_derive;
+/// # extern crate serde_json;
+/// # use or::Or;
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct Value {
+///     #[serde(with = "or::serde::b_first")]
+///     value: Or<String, u32>
+/// }
+///
+/// # fn main() {
+/// // A string could hold any value, so it has to be tried last.
+/// let number = serde_json::from_str::<Value>(r#"{"value": 5}"#).unwrap();
+/// assert_eq!(number.value, Or::B(5));
+///
+/// let string = serde_json::from_str::<Value>(r#"{"value": "five"}"#).unwrap();
+/// assert_eq!(string.value, Or::A("five".to_string()));
+///
+/// let err = serde_json::from_str::<Value>(r#"{"value": -5}"#).unwrap_err().to_string();
+/// assert!(err.starts_with("data did not match either side, as B: "));
+/// assert!(err.contains(", as A: "));
+/// # }
+/// ```
+pub mod b_first {
+    use serde_crate::de::{Deserialize, Deserializer};
+
+    use Or;
+
+    pub use super::untagged::serialize;
+
+    /// Deserializes an `Or`, trying `B` first and then `A`
+    pub fn deserialize<'de, A, B, D>(deserializer: D) -> Result<Or<A, B>, D::Error>
+    where A: Deserialize<'de>, B: Deserialize<'de>, D: Deserializer<'de> {
+        super::deserialize_in_order(deserializer, super::Order::BThenA)
+    }
+}
+
+// Defines a module of `serialize` and `deserialize` functions which use
+// the given serde enum representation for `Or`.
+macro_rules! representation {
+    ($(#[$attr:meta])* $name:ident, $($repr:tt)*) => {
+        $(#[$attr])*
+        pub mod $name {
+            use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+
+            use Or;
+
+            #[derive(Serialize)]
+            #[serde(crate = "::serde_crate", rename = "Or", $($repr)*)]
+            enum OrRef<'a, A: 'a, B: 'a> {
+                A(&'a A),
+                B(&'a B)
+            }
+
+            // Serde names the Rust type of the helper in some errors, so
+            // it has to be called `Or` too.
+            mod owned {
+                use serde_crate::Deserialize;
+
+                #[derive(Deserialize)]
+                #[serde(crate = "::serde_crate", $($repr)*)]
+                pub enum Or<A, B> {
+                    A(A),
+                    B(B)
+                }
+            }
+
+            /// Serializes an `Or` with this representation
+            pub fn serialize<A, B, S>(or: &Or<A, B>, serializer: S) -> Result<S::Ok, S::Error>
+            where A: Serialize, B: Serialize, S: Serializer {
+                match *or {
+                    Or::A(ref a) => OrRef::A::<A, B>(a),
+                    Or::B(ref b) => OrRef::B(b)
+                }.serialize(serializer)
+            }
+
+            /// Deserializes an `Or` with this representation
+            pub fn deserialize<'de, A, B, D>(deserializer: D) -> Result<Or<A, B>, D::Error>
+            where A: Deserialize<'de>, B: Deserialize<'de>, D: Deserializer<'de> {
+                Ok(match owned::Or::deserialize(deserializer)? {
+                    owned::Or::A(a) => Or::A(a),
+                    owned::Or::B(b) => Or::B(b)
+                })
+            }
+        }
+    }
+}
+
+representation! {
+    /// The internally tagged representation, where the variant is written
+    /// in a `type` field alongside the content's own fields
+    ///
+    /// Like any internally tagged enum, this only supports sides which
+    /// serialize as structs or maps.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # extern crate or;
+    /// # extern crate serde;
+    /// # #[macro_use] extern crate serde_derive;
+    /// # extern crate serde_json;
+    /// # use or::Or;
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Tcp { port: u16 }
+    ///
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Unix { path: String }
+    ///
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Listen {
+    ///     #[serde(with = "or::serde::internally_tagged")]
+    ///     on: Or<Tcp, Unix>
+    /// }
+    ///
+    /// # fn main() {
+    /// for (on, json) in vec![(Or::A(Tcp { port: 80 }), r#"{"on":{"type":"A","port":80}}"#),
+    ///                        (Or::B(Unix { path: "/run/sock".to_string() }),
+    ///                         r#"{"on":{"type":"B","path":"/run/sock"}}"#)] {
+    ///     let listen = Listen { on };
+    ///     assert_eq!(serde_json::to_string(&listen).unwrap(), json);
+    ///     assert_eq!(serde_json::from_str::<Listen>(json).unwrap(), listen);
+    /// }
+    /// # }
+    /// ```
+    internally_tagged, tag = "type"
+}
+
+representation! {
+    /// The adjacently tagged representation, where the variant is written
+    /// in a `tag` field and the content in a `content` field
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # extern crate or;
+    /// # extern crate serde;
+    /// # #[macro_use] extern crate serde_derive;
+    /// # extern crate serde_json;
+    /// # use or::Or;
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Port {
+    ///     #[serde(with = "or::serde::adjacently_tagged")]
+    ///     port: Or<u16, String>
+    /// }
+    ///
+    /// # fn main() {
+    /// for (port, json) in vec![(Or::A(80), r#"{"port":{"tag":"A","content":80}}"#),
+    ///                          (Or::B("http".to_string()),
+    ///                           r#"{"port":{"tag":"B","content":"http"}}"#)] {
+    ///     let port = Port { port };
+    ///     assert_eq!(serde_json::to_string(&port).unwrap(), json);
+    ///     assert_eq!(serde_json::from_str::<Port>(json).unwrap(), port);
+    /// }
+    ///
+    /// let err = serde_json::from_str::<Port>(r#"{"port":5}"#).unwrap_err().to_string();
+    /// assert!(err.starts_with("invalid type: integer `5`, expected adjacently tagged enum Or "));
+    /// # }
+    /// ```
+    adjacently_tagged, tag = "tag", content = "content"
+}
@@ -0,0 +1,343 @@
+//! A buffer for self-describing input, so that it can be deserialized more
+//! than once.
+//!
+//! Strings and bytes borrowed from the input stay borrowed, so types like
+//! `&str` can still be deserialized from the buffer.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde_crate::de::{self, Deserialize, DeserializeSeed, Deserializer, EnumAccess,
+                      IntoDeserializer, MapAccess, SeqAccess, Unexpected, VariantAccess,
+                      Visitor};
+use serde_crate::de::value::{MapDeserializer, SeqDeserializer};
+
+/// Any value of the serde data model
+pub enum Content<'de> {
+    Bool(bool),
+
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+
+    F32(f32),
+    F64(f64),
+
+    Char(char),
+    String(String),
+    Str(&'de str),
+    ByteBuf(Vec<u8>),
+    Bytes(&'de [u8]),
+
+    None,
+    Some(Box<Content<'de>>),
+
+    Unit,
+    Newtype(Box<Content<'de>>),
+    Seq(Vec<Content<'de>>),
+    Map(Vec<(Content<'de>, Content<'de>)>)
+}
+
+impl<'de> Content<'de> {
+    fn unexpected(&self) -> Unexpected<'_> {
+        match *self {
+            Content::Bool(b) => Unexpected::Bool(b),
+            Content::U8(n) => Unexpected::Unsigned(n as u64),
+            Content::U16(n) => Unexpected::Unsigned(n as u64),
+            Content::U32(n) => Unexpected::Unsigned(n as u64),
+            Content::U64(n) => Unexpected::Unsigned(n),
+            Content::U128(_) => Unexpected::Other("u128"),
+            Content::I8(n) => Unexpected::Signed(n as i64),
+            Content::I16(n) => Unexpected::Signed(n as i64),
+            Content::I32(n) => Unexpected::Signed(n as i64),
+            Content::I64(n) => Unexpected::Signed(n),
+            Content::I128(_) => Unexpected::Other("i128"),
+            Content::F32(f) => Unexpected::Float(f as f64),
+            Content::F64(f) => Unexpected::Float(f),
+            Content::Char(c) => Unexpected::Char(c),
+            Content::String(ref s) => Unexpected::Str(s),
+            Content::Str(s) => Unexpected::Str(s),
+            Content::ByteBuf(ref b) => Unexpected::Bytes(b),
+            Content::Bytes(b) => Unexpected::Bytes(b),
+            Content::None | Content::Some(_) => Unexpected::Option,
+            Content::Unit => Unexpected::Unit,
+            Content::Newtype(_) => Unexpected::NewtypeStruct,
+            Content::Seq(_) => Unexpected::Seq,
+            Content::Map(_) => Unexpected::Map
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Content<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: Deserializer<'de> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Content<'de>, E> { Ok(Content::Bool(v)) }
+
+    fn visit_u8<E>(self, v: u8) -> Result<Content<'de>, E> { Ok(Content::U8(v)) }
+    fn visit_u16<E>(self, v: u16) -> Result<Content<'de>, E> { Ok(Content::U16(v)) }
+    fn visit_u32<E>(self, v: u32) -> Result<Content<'de>, E> { Ok(Content::U32(v)) }
+    fn visit_u64<E>(self, v: u64) -> Result<Content<'de>, E> { Ok(Content::U64(v)) }
+    fn visit_u128<E>(self, v: u128) -> Result<Content<'de>, E> { Ok(Content::U128(v)) }
+
+    fn visit_i8<E>(self, v: i8) -> Result<Content<'de>, E> { Ok(Content::I8(v)) }
+    fn visit_i16<E>(self, v: i16) -> Result<Content<'de>, E> { Ok(Content::I16(v)) }
+    fn visit_i32<E>(self, v: i32) -> Result<Content<'de>, E> { Ok(Content::I32(v)) }
+    fn visit_i64<E>(self, v: i64) -> Result<Content<'de>, E> { Ok(Content::I64(v)) }
+    fn visit_i128<E>(self, v: i128) -> Result<Content<'de>, E> { Ok(Content::I128(v)) }
+
+    fn visit_f32<E>(self, v: f32) -> Result<Content<'de>, E> { Ok(Content::F32(v)) }
+    fn visit_f64<E>(self, v: f64) -> Result<Content<'de>, E> { Ok(Content::F64(v)) }
+
+    fn visit_char<E>(self, v: char) -> Result<Content<'de>, E> { Ok(Content::Char(v)) }
+
+    fn visit_str<E>(self, v: &str) -> Result<Content<'de>, E> {
+        Ok(Content::String(v.to_owned()))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Content<'de>, E> {
+        Ok(Content::Str(v))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Content<'de>, E> { Ok(Content::String(v)) }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Content<'de>, E> {
+        Ok(Content::ByteBuf(v.to_owned()))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Content<'de>, E> {
+        Ok(Content::Bytes(v))
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Content<'de>, E> {
+        Ok(Content::ByteBuf(v))
+    }
+
+    fn visit_none<E>(self) -> Result<Content<'de>, E> { Ok(Content::None) }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Content<'de>, D::Error>
+    where D: Deserializer<'de> {
+        Content::deserialize(deserializer).map(|v| Content::Some(Box::new(v)))
+    }
+
+    fn visit_unit<E>(self) -> Result<Content<'de>, E> { Ok(Content::Unit) }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Content<'de>, D::Error>
+    where D: Deserializer<'de> {
+        Content::deserialize(deserializer).map(|v| Content::Newtype(Box::new(v)))
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Content<'de>, V::Error>
+    where V: SeqAccess<'de> {
+        let mut vec = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(element) = seq.next_element()? {
+            vec.push(element);
+        }
+        Ok(Content::Seq(vec))
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Content<'de>, V::Error>
+    where V: MapAccess<'de> {
+        let mut vec = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some(entry) = map.next_entry()? {
+            vec.push(entry);
+        }
+        Ok(Content::Map(vec))
+    }
+}
+
+/// Deserializes from a reference to buffered `Content`, so that the same
+/// buffer can be deserialized again
+pub struct ContentRefDeserializer<'a, 'de: 'a, E> {
+    content: &'a Content<'de>,
+    err: PhantomData<E>
+}
+
+impl<'a, 'de, E> ContentRefDeserializer<'a, 'de, E> {
+    pub fn new(content: &'a Content<'de>) -> Self {
+        ContentRefDeserializer { content, err: PhantomData }
+    }
+}
+
+impl<'a, 'de, E> Deserializer<'de> for ContentRefDeserializer<'a, 'de, E>
+where E: de::Error {
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where V: Visitor<'de> {
+        match *self.content {
+            Content::Bool(v) => visitor.visit_bool(v),
+            Content::U8(v) => visitor.visit_u8(v),
+            Content::U16(v) => visitor.visit_u16(v),
+            Content::U32(v) => visitor.visit_u32(v),
+            Content::U64(v) => visitor.visit_u64(v),
+            Content::U128(v) => visitor.visit_u128(v),
+            Content::I8(v) => visitor.visit_i8(v),
+            Content::I16(v) => visitor.visit_i16(v),
+            Content::I32(v) => visitor.visit_i32(v),
+            Content::I64(v) => visitor.visit_i64(v),
+            Content::I128(v) => visitor.visit_i128(v),
+            Content::F32(v) => visitor.visit_f32(v),
+            Content::F64(v) => visitor.visit_f64(v),
+            Content::Char(v) => visitor.visit_char(v),
+            Content::String(ref v) => visitor.visit_str(v),
+            Content::Str(v) => visitor.visit_borrowed_str(v),
+            Content::ByteBuf(ref v) => visitor.visit_bytes(v),
+            Content::Bytes(v) => visitor.visit_borrowed_bytes(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(ref v) => visitor.visit_some(ContentRefDeserializer::new(v)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(ref v) => visitor.visit_newtype_struct(ContentRefDeserializer::new(v)),
+            Content::Seq(ref v) => {
+                let mut seq = SeqDeserializer::new(v.iter().map(ContentRefDeserializer::new));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Content::Map(ref v) => {
+                let mut map = MapDeserializer::new(v.iter().map(|(k, v)| {
+                    (ContentRefDeserializer::new(k), ContentRefDeserializer::new(v))
+                }));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where V: Visitor<'de> {
+        match *self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(ref v) => visitor.visit_some(ContentRefDeserializer::new(v)),
+            _ => visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V)
+                                     -> Result<V::Value, E>
+    where V: Visitor<'de> {
+        match *self.content {
+            Content::Newtype(ref v) => visitor.visit_newtype_struct(ContentRefDeserializer::new(v)),
+            _ => visitor.visit_newtype_struct(self)
+        }
+    }
+
+    fn deserialize_enum<V>(self, _name: &'static str, _variants: &'static [&'static str],
+                           visitor: V) -> Result<V::Value, E>
+    where V: Visitor<'de> {
+        let (variant, value) = match *self.content {
+            Content::String(_) | Content::Str(_) => (self.content, None),
+            Content::Map(ref entries) if entries.len() == 1 => {
+                (&entries[0].0, Some(&entries[0].1))
+            }
+            ref other => return Err(E::invalid_type(other.unexpected(), &"string or map"))
+        };
+
+        visitor.visit_enum(EnumRefDeserializer { variant, value, err: PhantomData })
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where V: Visitor<'de> {
+        visitor.visit_unit()
+    }
+
+    serde_crate::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+impl<'a, 'de, E> IntoDeserializer<'de, E> for ContentRefDeserializer<'a, 'de, E>
+where E: de::Error {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self { self }
+}
+
+struct EnumRefDeserializer<'a, 'de: 'a, E> {
+    variant: &'a Content<'de>,
+    value: Option<&'a Content<'de>>,
+    err: PhantomData<E>
+}
+
+impl<'a, 'de, E> EnumAccess<'de> for EnumRefDeserializer<'a, 'de, E>
+where E: de::Error {
+    type Error = E;
+    type Variant = VariantRefDeserializer<'a, 'de, E>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), E>
+    where V: DeserializeSeed<'de> {
+        let variant = seed.deserialize(ContentRefDeserializer::new(self.variant))?;
+        Ok((variant, VariantRefDeserializer { value: self.value, err: PhantomData }))
+    }
+}
+
+struct VariantRefDeserializer<'a, 'de: 'a, E> {
+    value: Option<&'a Content<'de>>,
+    err: PhantomData<E>
+}
+
+impl<'a, 'de, E> VariantAccess<'de> for VariantRefDeserializer<'a, 'de, E>
+where E: de::Error {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            None | Some(&Content::Unit) => Ok(()),
+            Some(other) => Err(E::invalid_type(other.unexpected(), &"unit variant"))
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, E>
+    where T: DeserializeSeed<'de> {
+        match self.value {
+            Some(value) => seed.deserialize(ContentRefDeserializer::new(value)),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"newtype variant"))
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, E>
+    where V: Visitor<'de> {
+        match self.value {
+            Some(value @ &Content::Seq(_)) => {
+                ContentRefDeserializer::new(value).deserialize_any(visitor)
+            }
+            Some(other) => Err(E::invalid_type(other.unexpected(), &"tuple variant")),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"tuple variant"))
+        }
+    }
+
+    fn struct_variant<V>(self, _fields: &'static [&'static str], visitor: V)
+                         -> Result<V::Value, E>
+    where V: Visitor<'de> {
+        match self.value {
+            Some(value @ &Content::Map(_)) | Some(value @ &Content::Seq(_)) => {
+                ContentRefDeserializer::new(value).deserialize_any(visitor)
+            }
+            Some(other) => Err(E::invalid_type(other.unexpected(), &"struct variant")),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"struct variant"))
+        }
+    }
+}
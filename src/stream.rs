@@ -0,0 +1,115 @@
+//! `Stream` and `Sink` support for `Or`, behind the `futures` feature.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::sink::Sink;
+use futures::stream::{FusedStream, Stream};
+
+use Or;
+
+/// `Or` is a stream if both sides are streams with the same `Item`.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate or;
+/// # use or::Or;
+/// use futures::executor::block_on;
+/// use futures::stream::{self, Stream, StreamExt};
+///
+/// # fn main() {
+/// fn numbers(live: bool) -> Or<stream::Iter<std::ops::Range<i32>>, stream::Repeat<i32>> {
+///     if live { Or::A(stream::iter(0..3)) } else { Or::B(stream::repeat(7)) }
+/// }
+///
+/// assert_eq!(numbers(true).size_hint(), (3, Some(3)));
+/// assert_eq!(block_on(numbers(true).collect::<Vec<_>>()), vec![0, 1, 2]);
+/// assert_eq!(block_on(numbers(false).take(2).collect::<Vec<_>>()), vec![7, 7]);
+/// # }
+/// ```
+impl<A, B> Stream for Or<A, B>
+where A: Stream, B: Stream<Item = A::Item> {
+    type Item = A::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<A::Item>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_next(cx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        for_both!(*self, ref inner => inner.size_hint())
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate or;
+/// # use or::Or;
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+/// use futures::stream::FusedStream;
+///
+/// # fn main() {
+/// let mut x: Or<_, stream::Fuse<stream::Empty<i32>>> = Or::A(stream::iter(0..1).fuse());
+///
+/// assert!(!x.is_terminated());
+/// assert_eq!(block_on(x.next()), Some(0));
+/// assert_eq!(block_on(x.next()), None);
+/// assert!(x.is_terminated());
+/// # }
+/// ```
+impl<A, B> FusedStream for Or<A, B>
+where A: FusedStream, B: FusedStream<Item = A::Item> {
+    fn is_terminated(&self) -> bool {
+        for_both!(*self, ref inner => inner.is_terminated())
+    }
+}
+
+/// `Or` is a sink if both sides are sinks with the same `Error`.
+///
+/// ## Example
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate or;
+/// # use or::Or;
+/// use futures::executor::block_on;
+/// use futures::sink::{self, SinkExt};
+/// use futures::stream::{self, StreamExt};
+/// use std::convert::Infallible;
+///
+/// # fn main() {
+/// let mut buffered: Vec<i32> = Vec::new();
+/// let mut sink: Or<&mut Vec<i32>, sink::Drain<i32>> = Or::A(&mut buffered);
+///
+/// block_on(sink.send_all(&mut stream::iter(vec![Ok::<_, Infallible>(1), Ok(2)]))).unwrap();
+/// block_on(sink.close()).unwrap();
+/// assert_eq!(buffered, vec![1, 2]);
+///
+/// let mut dropped: Or<Vec<i32>, _> = Or::B(sink::drain());
+/// block_on(dropped.send(3)).unwrap();
+/// # }
+/// ```
+impl<A, B, Item> Sink<Item> for Or<A, B>
+where A: Sink<Item>, B: Sink<Item, Error = A::Error> {
+    type Error = A::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<(), A::Error>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_ready(cx))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), A::Error> {
+        for_both!(self.as_pin_mut(), inner => inner.start_send(item))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<(), A::Error>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_flush(cx))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<(), A::Error>> {
+        for_both!(self.as_pin_mut(), inner => inner.poll_close(cx))
+    }
+}
@@ -0,0 +1,334 @@
+//! `These`, for one or both of two values.
+
+use std::iter::FromIterator;
+
+use Or;
+
+/// Either an `A`, a `B`, or both
+///
+/// Where `Or` holds exactly one of its sides, `These` holds at least one,
+/// as when merging two sources where a key may appear in either or both.
+///
+/// As with `Or`, most functionality is available through the `a` and `b`
+/// projections, combined with `as_ref` and `as_mut`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde_crate::Serialize, serde_crate::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "::serde_crate"))]
+pub enum These<A, B> {
+    /// Only an `A`
+    This(A),
+    /// Only a `B`
+    That(B),
+    /// Both an `A` and a `B`
+    Both(A, B)
+}
+
+impl<A, B> These<A, B> {
+    /// Returns true if the `These` is `This`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let x: These<i32, ()> = These::This(545);
+    /// assert!(x.is_this());
+    ///
+    /// let y: These<i32, ()> = These::Both(545, ());
+    /// assert!(!y.is_this());
+    /// ```
+    pub fn is_this(&self) -> bool {
+        matches!(*self, These::This(_))
+    }
+
+    /// Returns true if the `These` is `That`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let x: These<(), i32> = These::That(2);
+    /// assert!(x.is_that());
+    ///
+    /// let y: These<(), i32> = These::Both((), 2);
+    /// assert!(!y.is_that());
+    /// ```
+    pub fn is_that(&self) -> bool {
+        matches!(*self, These::That(_))
+    }
+
+    /// Returns true if the `These` is `Both`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let x: These<i32, &str> = These::Both(2, "two");
+    /// assert!(x.is_both());
+    ///
+    /// let y: These<i32, &str> = These::This(2);
+    /// assert!(!y.is_both());
+    /// ```
+    pub fn is_both(&self) -> bool {
+        matches!(*self, These::Both(..))
+    }
+
+    /// Converts from `These<A, B>` to `Option<A>`
+    ///
+    /// This method consumes `self` and discards `B`, if any. The `A` is
+    /// returned from either `This` or `Both`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let x: These<i32, &str> = These::Both(2, "two");
+    /// assert_eq!(x.a(), Some(2));
+    ///
+    /// let y: These<i32, &str> = These::That("two");
+    /// assert!(y.a().is_none());
+    /// ```
+    pub fn a(self) -> Option<A> {
+        match self {
+            These::This(a) | These::Both(a, _) => Some(a),
+            These::That(_) => None
+        }
+    }
+
+    /// Converts from `These<A, B>` to `Option<B>`
+    ///
+    /// This method consumes `self` and discards `A`, if any. The `B` is
+    /// returned from either `That` or `Both`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let x: These<i32, &str> = These::Both(2, "two");
+    /// assert_eq!(x.b(), Some("two"));
+    ///
+    /// let y: These<i32, &str> = These::This(2);
+    /// assert!(y.b().is_none());
+    /// ```
+    pub fn b(self) -> Option<B> {
+        match self {
+            These::That(b) | These::Both(_, b) => Some(b),
+            These::This(_) => None
+        }
+    }
+
+    /// Convert from `&These<A, B>` to `These<&A, &B>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let x: These<String, i32> = These::Both("hello".to_string(), 2);
+    /// assert_eq!(&**x.as_ref().a().unwrap(), "hello");
+    /// ```
+    pub fn as_ref(&self) -> These<&A, &B> {
+        match *self {
+            These::This(ref a) => These::This(a),
+            These::That(ref b) => These::That(b),
+            These::Both(ref a, ref b) => These::Both(a, b)
+        }
+    }
+
+    /// Convert from `&mut These<A, B>` to `These<&mut A, &mut B>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let mut x: These<String, i32> = These::Both("hello".to_string(), 2);
+    /// x.as_mut().a().map(|s| s.push_str(" world!"));
+    ///
+    /// assert_eq!(&**x.as_ref().a().unwrap(), "hello world!");
+    /// ```
+    pub fn as_mut(&mut self) -> These<&mut A, &mut B> {
+        match *self {
+            These::This(ref mut a) => These::This(a),
+            These::That(ref mut b) => These::That(b),
+            These::Both(ref mut a, ref mut b) => These::Both(a, b)
+        }
+    }
+
+    /// Convert from `These<A, B>` to `These<B, A>`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let x: These<i32, &str> = These::Both(2, "two");
+    /// assert_eq!(x.swap(), These::Both("two", 2));
+    /// ```
+    pub fn swap(self) -> These<B, A> {
+        match self {
+            These::This(a) => These::That(a),
+            These::That(b) => These::This(b),
+            These::Both(a, b) => These::Both(b, a)
+        }
+    }
+
+    /// Convert from `Or<A, B>` to `These<A, B>`
+    ///
+    /// `A` becomes `This` and `B` becomes `That`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, These};
+    /// assert_eq!(These::from_or(Or::A::<i32, &str>(2)), These::This(2));
+    /// assert_eq!(These::from_or(Or::B::<i32, &str>("two")), These::That("two"));
+    /// ```
+    pub fn from_or(or: Or<A, B>) -> Self {
+        match or {
+            Or::A(a) => These::This(a),
+            Or::B(b) => These::That(b)
+        }
+    }
+
+    /// Convert from `These<A, B>` to `Or<A, B>`
+    ///
+    /// `Both` can't be represented as an `Or`, and is returned as the
+    /// error.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::{Or, These};
+    /// assert_eq!(These::This::<i32, &str>(2).try_into_or(), Ok(Or::A(2)));
+    /// assert_eq!(These::That::<i32, &str>("two").try_into_or(), Ok(Or::B("two")));
+    /// assert_eq!(These::Both(2, "two").try_into_or(), Err((2, "two")));
+    /// ```
+    pub fn try_into_or(self) -> Result<Or<A, B>, (A, B)> {
+        match self {
+            These::This(a) => Ok(Or::A(a)),
+            These::That(b) => Ok(Or::B(b)),
+            These::Both(a, b) => Err((a, b))
+        }
+    }
+
+    /// Convert from `These<A, B>` to a pair of `Option`s, at least one of
+    /// which is `Some`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// assert_eq!(These::This::<i32, &str>(2).into_options(), (Some(2), None));
+    /// assert_eq!(These::Both(2, "two").into_options(), (Some(2), Some("two")));
+    /// ```
+    pub fn into_options(self) -> (Option<A>, Option<B>) {
+        match self {
+            These::This(a) => (Some(a), None),
+            These::That(b) => (None, Some(b)),
+            These::Both(a, b) => (Some(a), Some(b))
+        }
+    }
+
+    /// Convert from a pair of `Option`s to `These<A, B>`
+    ///
+    /// Returns `None` if neither `Option` is `Some`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// assert_eq!(These::from_options(Some(2), Some("two")), Some(These::Both(2, "two")));
+    /// assert_eq!(These::from_options(None::<i32>, Some("two")), Some(These::That("two")));
+    /// assert_eq!(These::from_options(None::<i32>, None::<&str>), None);
+    /// ```
+    pub fn from_options(a: Option<A>, b: Option<B>) -> Option<Self> {
+        match (a, b) {
+            (Some(a), None) => Some(These::This(a)),
+            (None, Some(b)) => Some(These::That(b)),
+            (Some(a), Some(b)) => Some(These::Both(a, b)),
+            (None, None) => None
+        }
+    }
+}
+
+impl<T> These<T, T> {
+    /// Collapses a `These` with the same type on both sides to a single
+    /// value, combining the values of `Both` with `f`
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use or::These;
+    /// let x: These<i32, i32> = These::Both(2, 3);
+    /// assert_eq!(x.merge(|a, b| a + b), 5);
+    ///
+    /// let y: These<i32, i32> = These::That(3);
+    /// assert_eq!(y.merge(|a, b| a + b), 3);
+    /// ```
+    pub fn merge<F>(self, f: F) -> T
+    where F: FnOnce(T, T) -> T {
+        match self {
+            These::This(t) | These::That(t) => t,
+            These::Both(a, b) => f(a, b)
+        }
+    }
+}
+
+/// ## Example
+///
+/// ```rust
+/// # use or::{Or, These};
+/// let x: These<i32, &str> = Or::B("two").into();
+/// assert_eq!(x, These::That("two"));
+/// ```
+impl<A, B> From<Or<A, B>> for These<A, B> {
+    fn from(or: Or<A, B>) -> Self {
+        These::from_or(or)
+    }
+}
+
+/// Collects `A`s and `B`s into a pair of collections, with `Both` adding
+/// to each of them.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::These;
+/// let items = vec![These::This(1), These::Both(2, "two"), These::That("three")];
+/// let (numbers, names): (Vec<i32>, Vec<&str>) = items.into_iter().collect();
+///
+/// assert_eq!(numbers, vec![1, 2]);
+/// assert_eq!(names, vec!["two", "three"]);
+/// ```
+impl<A, B, CA, CB> FromIterator<These<A, B>> for (CA, CB)
+where CA: Default + Extend<A>, CB: Default + Extend<B> {
+    fn from_iter<I>(iter: I) -> (CA, CB)
+    where I: IntoIterator<Item = These<A, B>> {
+        let mut collections = (CA::default(), CB::default());
+        collections.extend(iter);
+        collections
+    }
+}
+
+/// Extends a pair of collections with `A`s and `B`s respectively, with
+/// `Both` adding to each of them.
+///
+/// ## Example
+///
+/// ```rust
+/// # use or::{Or, These};
+/// let mut pair: (Vec<i32>, Vec<&str>) = (vec![], vec![]);
+///
+/// pair.extend(vec![Or::A(1), Or::B("one")]);
+/// pair.extend(vec![These::Both(2, "two")]);
+///
+/// assert_eq!(pair, (vec![1, 2], vec!["one", "two"]));
+/// ```
+impl<A, B, CA, CB> Extend<These<A, B>> for (CA, CB)
+where CA: Extend<A>, CB: Extend<B> {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = These<A, B>> {
+        for item in iter {
+            let (a, b) = item.into_options();
+            self.0.extend(a);
+            self.1.extend(b);
+        }
+    }
+}